@@ -0,0 +1,33 @@
+use core::cell::RefCell;
+use embedded_hal::adc::OneShot;
+use linux_embedded_hal::I2cdev;
+use nb::block;
+
+use ads1x1x::{bus::RefCellBus, channel, Ads1x1x, SlaveAddr};
+
+fn main() {
+    let dev = RefCell::new(I2cdev::new("/dev/i2c-1").unwrap());
+    let addresses = [
+        SlaveAddr::new_gnd(),
+        SlaveAddr::new_vdd(),
+        SlaveAddr::new_sda(),
+        SlaveAddr::new_scl(),
+    ];
+    let mut adcs = addresses.map(|address| Ads1x1x::new_ads1115(RefCellBus::new(&dev), address));
+    for (index, adc) in adcs.iter_mut().enumerate() {
+        let values = [
+            block!(adc.read(&mut channel::SingleA0)).unwrap(),
+            block!(adc.read(&mut channel::SingleA1)).unwrap(),
+            block!(adc.read(&mut channel::SingleA2)).unwrap(),
+            block!(adc.read(&mut channel::SingleA3)).unwrap(),
+        ];
+        for (channel, value) in values.iter().enumerate() {
+            println!("ADC {} channel {}: {}", index, channel, value);
+        }
+    }
+    // get I2C device back
+    for adc in adcs {
+        let _dev = adc.destroy_ads1115();
+    }
+    let _dev = dev.into_inner();
+}
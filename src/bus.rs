@@ -0,0 +1,539 @@
+//! Functions addressing the devices on an I2C bus instead of a single device.
+//!
+//! These take the I2C bus directly so that they can be used when several
+//! ADS1x1x devices share one bus.
+//!
+//! To create several driver instances on one bus, each of them can be given a
+//! [`RefCellBus`](struct.RefCellBus.html) referring to the same bus.
+//! With the `eh1` feature, the bus sharing implementations of the
+//! `embedded-hal-bus` crate can be used as well, wrapped in
+//! [`Eh1`](../struct.Eh1.html).
+
+use crate::{
+    ic, interface, BitFlags, Config, Error, Operation, Register, SlaveAddr, ALERT_RESPONSE_ADDRESS,
+    DEVICE_BASE_ADDRESS,
+};
+#[cfg(any(feature = "eh02", feature = "eh1"))]
+use core::cell::RefCell;
+#[cfg(feature = "eh02")]
+use embedded_hal::blocking;
+#[cfg(feature = "eh1")]
+use embedded_hal_1::i2c;
+#[cfg(feature = "async")]
+use embedded_hal_async::{delay::DelayNs as AsyncDelayNs, i2c::I2c as AsyncI2c};
+
+/// Addresses selectable with the `ADDR` pin in the order
+/// GND, VDD, SDA, SCL.
+const ADDRESSES: [SlaveAddr; 4] = [
+    SlaveAddr::Default,
+    SlaveAddr::Alternative(false, true),
+    SlaveAddr::Alternative(true, false),
+    SlaveAddr::Alternative(true, true),
+];
+
+/// Value of the configuration register after power-up or reset.
+const POWER_UP_CONFIG: u16 = 0x8583;
+
+/// Number of conversions used to determine the resolution of a device in
+/// continuous-conversion mode.
+const RESOLUTION_SAMPLES: usize = 4;
+
+/// Data rate bits selecting the highest data rate: 3300 SPS on 12-bit and
+/// 860 SPS on 16-bit devices.
+const FASTEST_DATA_RATE: u16 = BitFlags::DR2 | BitFlags::DR1 | BitFlags::DR0;
+
+/// Time after triggering a conversion at the highest data rate at which a
+/// 12-bit device has finished (after at most about 0.36 ms) while a 16-bit
+/// device is still converting (for at least about 1.05 ms).
+const RESOLUTION_PROBE_TIME_US: u32 = 500;
+
+/// Resolution of a device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    /// 12-bit device (ADS101x)
+    Bits12,
+    /// 16-bit device (ADS111x)
+    Bits16,
+}
+
+/// Device found by [`scan()`](fn.scan.html)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProbedDevice {
+    /// Address of the device
+    pub address: SlaveAddr,
+    /// Whether the configuration register holds its power-up value 0x8583
+    pub power_up_config: bool,
+    /// Resolution of the device, `None` if it could not be determined
+    pub resolution: Option<Resolution>,
+}
+
+/// Whether the configuration register value is that of a device in
+/// single-shot mode which is not converting, like the power-up value.
+///
+/// Other devices may respond at the same addresses, so nothing is written
+/// to a device unless its configuration register looks like this.
+fn is_idle_single_shot(config: u16) -> bool {
+    let flags = BitFlags::OS | BitFlags::OP_MODE;
+    config & flags == flags
+}
+
+/// Whether the configuration register value is that of a device in
+/// continuous-conversion mode with the comparator disabled.
+///
+/// Other devices may respond at the same addresses, so their conversion
+/// register is only sampled if their configuration register looks like
+/// this. For example, the TMP102 power-up value 0x60A0 would otherwise be
+/// taken for an ADS1x1x in continuous-conversion mode with the comparator
+/// enabled.
+fn is_continuous_without_comparator(config: u16) -> bool {
+    let queue = BitFlags::COMP_QUE1 | BitFlags::COMP_QUE0;
+    config & BitFlags::OP_MODE == 0 && config & queue == queue
+}
+
+/// Decide on the resolution from the values of several conversions.
+///
+/// 12-bit devices always return zero in the four least-significant bits of
+/// the conversion register. This is also the case for a 16-bit device whose
+/// input is steady at a multiple of 16 codes, which is then wrongly reported
+/// as a 12-bit device. All-zero and negative full-scale readings are treated
+/// as ambiguous.
+fn resolution_from_samples(samples: &[u16]) -> Option<Resolution> {
+    if samples.iter().any(|value| value & 0xF != 0) {
+        Some(Resolution::Bits16)
+    } else if samples.iter().any(|&value| value != 0 && value != 0x8000) {
+        Some(Resolution::Bits12)
+    } else {
+        None
+    }
+}
+
+/// Decide on the resolution from whether a conversion at the highest data
+/// rate is still in progress after `RESOLUTION_PROBE_TIME_US`.
+fn resolution_from_timing(config_after_probe_time: u16) -> Resolution {
+    if config_after_probe_time & BitFlags::OS == 0 {
+        Resolution::Bits16
+    } else {
+        Resolution::Bits12
+    }
+}
+
+/// Delay between conversions while probing.
+///
+/// The resolution is not known yet so the longer conversion time of the
+/// 16-bit devices is used.
+fn probe_conversion_time_us(config: u16) -> u32 {
+    Config { bits: config }.conversion_time_us::<ic::Resolution16Bit>()
+}
+
+fn probe<DI, E, D>(iface: &mut DI, address: SlaveAddr, delay: &mut D) -> Option<ProbedDevice>
+where
+    DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
+    D: interface::Delay,
+{
+    let config = iface.read_register(Register::CONFIG).ok()?;
+    Some(ProbedDevice {
+        address,
+        power_up_config: config == POWER_UP_CONFIG,
+        resolution: probe_resolution(iface, config, delay).unwrap_or(None),
+    })
+}
+
+/// Determine the resolution of a device.
+///
+/// In single-shot mode, a conversion at the highest data rate is timed and
+/// the data rate is restored afterwards. In continuous-conversion mode with
+/// the comparator disabled, nothing is written and the resolution is decided
+/// from the values of a few conversions instead. Otherwise the resolution is
+/// not determined.
+fn probe_resolution<DI, E, D>(
+    iface: &mut DI,
+    config: u16,
+    delay: &mut D,
+) -> Result<Option<Resolution>, Error<E>>
+where
+    DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
+    D: interface::Delay,
+{
+    if is_continuous_without_comparator(config) {
+        let mut samples = [0; RESOLUTION_SAMPLES];
+        for sample in samples.iter_mut() {
+            delay.wait_us(probe_conversion_time_us(config));
+            *sample = iface.read_register(Register::CONVERSION)?;
+        }
+        return Ok(resolution_from_samples(&samples));
+    }
+    if !is_idle_single_shot(config) {
+        return Ok(None);
+    }
+    iface.write_register(Register::CONFIG, config | FASTEST_DATA_RATE)?;
+    delay.wait_us(RESOLUTION_PROBE_TIME_US);
+    let config_after_probe_time = iface.read_register(Register::CONFIG)?;
+    // without OS so that no further conversion is triggered
+    iface.write_register(Register::CONFIG, config & !BitFlags::OS)?;
+    Ok(Some(resolution_from_timing(config_after_probe_time)))
+}
+
+fn read_resolution<DI, E, D>(iface: &mut DI, delay: &mut D) -> Result<Option<Resolution>, Error<E>>
+where
+    DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
+    D: interface::Delay,
+{
+    let config = iface.read_register(Register::CONFIG)?;
+    probe_resolution(iface, config, delay)
+}
+
+struct BorrowedBus<'a, I2C>(&'a mut I2C);
+
+impl<I2C: interface::I2cWrite> interface::I2cWrite for BorrowedBus<'_, I2C> {
+    type Error = I2C::Error;
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write(address, bytes)
+    }
+}
+
+impl<I2C: interface::I2cWriteRead> interface::I2cWriteRead for BorrowedBus<'_, I2C> {
+    type Error = I2C::Error;
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.0.write_read(address, bytes, buffer)
+    }
+}
+
+/// I2C bus shared between several driver instances in the same thread
+///
+/// This forwards the I2C operations to the bus in the `RefCell`, which is
+/// only borrowed for the duration of each operation.
+///
+/// ```no_run
+/// # #[cfg(feature = "eh02")]
+/// # fn main() {
+/// use ads1x1x::{bus::RefCellBus, Ads1x1x, SlaveAddr};
+/// use core::cell::RefCell;
+/// use linux_embedded_hal::I2cdev;
+///
+/// let i2c = RefCell::new(I2cdev::new("/dev/i2c-1").unwrap());
+/// let adc_gnd = Ads1x1x::new_ads1115(RefCellBus::new(&i2c), SlaveAddr::new_gnd());
+/// let adc_vdd = Ads1x1x::new_ads1115(RefCellBus::new(&i2c), SlaveAddr::new_vdd());
+/// # }
+/// # #[cfg(not(feature = "eh02"))]
+/// # fn main() {}
+/// ```
+#[cfg(any(feature = "eh02", feature = "eh1"))]
+#[derive(Debug)]
+pub struct RefCellBus<'a, I2C> {
+    bus: &'a RefCell<I2C>,
+}
+
+#[cfg(any(feature = "eh02", feature = "eh1"))]
+impl<'a, I2C> RefCellBus<'a, I2C> {
+    /// Create a handle to the shared bus.
+    pub fn new(bus: &'a RefCell<I2C>) -> Self {
+        RefCellBus { bus }
+    }
+}
+
+#[cfg(feature = "eh02")]
+impl<I2C: blocking::i2c::Write> blocking::i2c::Write for RefCellBus<'_, I2C> {
+    type Error = I2C::Error;
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().write(address, bytes)
+    }
+}
+
+#[cfg(feature = "eh02")]
+impl<I2C: blocking::i2c::WriteRead> blocking::i2c::WriteRead for RefCellBus<'_, I2C> {
+    type Error = I2C::Error;
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().write_read(address, bytes, buffer)
+    }
+}
+
+#[cfg(feature = "eh02")]
+impl<I2C: blocking::i2c::Read> blocking::i2c::Read for RefCellBus<'_, I2C> {
+    type Error = I2C::Error;
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().read(address, buffer)
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<I2C: i2c::ErrorType> i2c::ErrorType for RefCellBus<'_, I2C> {
+    type Error = I2C::Error;
+}
+
+#[cfg(feature = "eh1")]
+impl<I2C: i2c::I2c> i2c::I2c for RefCellBus<'_, I2C> {
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().read(address, buffer)
+    }
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().write(address, bytes)
+    }
+
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().write_read(address, bytes, buffer)
+    }
+
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.bus.borrow_mut().transaction(address, operations)
+    }
+}
+
+/// Probe the four addresses selectable with the `ADDR` pin for ADS1x1x
+/// devices.
+///
+/// The result contains the devices found in the order GND, VDD, SDA, SCL.
+/// An address is considered empty if reading the configuration register
+/// fails. Other devices responding at these addresses, like some
+/// temperature sensors, are reported as well.
+///
+/// To tell 12-bit from 16-bit devices, devices in single-shot mode which are
+/// not converting, for example because they hold the power-up
+/// configuration, make a conversion at the highest data rate. 12-bit devices
+/// finish it within `0.5 ms`, 16-bit devices do not. The configuration is
+/// restored afterwards. This assumes that the delay does not take
+/// considerably longer than requested.
+/// Nothing is written to devices in other states. For devices in
+/// continuous-conversion mode with the comparator disabled, the resolution
+/// is decided from the values of a few conversions: 12-bit devices always return zero in the four
+/// least-significant bits of the conversion register. This wrongly reports
+/// a 16-bit device as a 12-bit device if its input is steady at a multiple
+/// of 16 codes, and the resolution cannot be determined if all conversions
+/// read zero or negative full-scale.
+pub fn scan<I2C, E, D>(i2c: &mut I2C, delay: &mut D) -> [Option<ProbedDevice>; 4]
+where
+    I2C: interface::I2cWrite<Error = E> + interface::I2cWriteRead<Error = E>,
+    D: interface::Delay,
+{
+    let mut found = [None; 4];
+    for (device, address) in found.iter_mut().zip(ADDRESSES) {
+        let mut iface = interface::I2cInterface {
+            i2c: BorrowedBus(&mut *i2c),
+            address: address.addr(DEVICE_BASE_ADDRESS),
+        };
+        *device = probe(&mut iface, address, delay);
+    }
+    found
+}
+
+/// Detect the resolution of the device at the given address.
+///
+/// The resolution is determined like [`scan()`](fn.scan.html) does,
+/// so it cannot be determined if the device is converting in single-shot
+/// mode.
+///
+/// The remaining differences between the variants cannot be detected: the
+/// full-scale range and input multiplexer bits of the configuration register
+/// can be written and read back on all variants. To create a
+/// [`DynamicAds1x1x`](../enum.DynamicAds1x1x.html), combine the detected
+/// resolution with the features known from the board design using
+/// [`Variant::from_features()`](../enum.Variant.html#method.from_features).
+pub fn detect_resolution<I2C, E, D>(
+    i2c: &mut I2C,
+    address: SlaveAddr,
+    delay: &mut D,
+) -> Result<Option<Resolution>, Error<E>>
+where
+    I2C: interface::I2cWrite<Error = E> + interface::I2cWriteRead<Error = E>,
+    D: interface::Delay,
+{
+    let mut iface = interface::I2cInterface {
+        i2c: BorrowedBus(i2c),
+        address: address.addr(DEVICE_BASE_ADDRESS),
+    };
+    read_resolution(&mut iface, delay)
+}
+
+/// Probe the four addresses selectable with the `ADDR` pin for ADS1x1x
+/// devices on an asynchronous I2C bus.
+///
+/// See [`scan()`](fn.scan.html).
+#[cfg(feature = "async")]
+pub async fn scan_async<I2C, E, D>(i2c: &mut I2C, delay: &mut D) -> [Option<ProbedDevice>; 4]
+where
+    I2C: AsyncI2c<Error = E>,
+    D: AsyncDelayNs,
+{
+    let mut found = [None; 4];
+    for (device, address) in found.iter_mut().zip(ADDRESSES) {
+        let mut iface = interface::AsyncI2cInterface {
+            i2c: &mut *i2c,
+            address: address.addr(DEVICE_BASE_ADDRESS),
+        };
+        *device = probe_async(&mut iface, address, delay).await;
+    }
+    found
+}
+
+#[cfg(feature = "async")]
+async fn probe_async<I2C, E, D>(
+    iface: &mut interface::AsyncI2cInterface<I2C>,
+    address: SlaveAddr,
+    delay: &mut D,
+) -> Option<ProbedDevice>
+where
+    I2C: AsyncI2c<Error = E>,
+    D: AsyncDelayNs,
+{
+    let config = iface.read_register(Register::CONFIG).await.ok()?;
+    Some(ProbedDevice {
+        address,
+        power_up_config: config == POWER_UP_CONFIG,
+        resolution: probe_resolution_async(iface, config, delay)
+            .await
+            .unwrap_or(None),
+    })
+}
+
+/// See [`probe_resolution()`](fn.probe_resolution.html).
+#[cfg(feature = "async")]
+async fn probe_resolution_async<I2C, E, D>(
+    iface: &mut interface::AsyncI2cInterface<I2C>,
+    config: u16,
+    delay: &mut D,
+) -> Result<Option<Resolution>, Error<E>>
+where
+    I2C: AsyncI2c<Error = E>,
+    D: AsyncDelayNs,
+{
+    if is_continuous_without_comparator(config) {
+        let mut samples = [0; RESOLUTION_SAMPLES];
+        for sample in samples.iter_mut() {
+            delay.delay_us(probe_conversion_time_us(config)).await;
+            *sample = iface.read_register(Register::CONVERSION).await?;
+        }
+        return Ok(resolution_from_samples(&samples));
+    }
+    if !is_idle_single_shot(config) {
+        return Ok(None);
+    }
+    iface
+        .write_register(Register::CONFIG, config | FASTEST_DATA_RATE)
+        .await?;
+    delay.delay_us(RESOLUTION_PROBE_TIME_US).await;
+    let config_after_probe_time = iface.read_register(Register::CONFIG).await?;
+    // without OS so that no further conversion is triggered
+    iface
+        .write_register(Register::CONFIG, config & !BitFlags::OS)
+        .await?;
+    Ok(Some(resolution_from_timing(config_after_probe_time)))
+}
+
+fn decode_alert_response(response: u8) -> Option<SlaveAddr> {
+    SlaveAddr::from_addr(response >> 1, DEVICE_BASE_ADDRESS)
+}
+
+/// Send an SMBus alert response and return the address of the device
+/// which asserted the ALERT/RDY line.
+///
+/// This reads from the SMBus Alert Response Address (0x0C). If several
+/// devices assert the shared ALERT/RDY line, the one with the lowest address
+/// responds and clears its ALERT/RDY pin if it is latching.
+/// Calling this repeatedly identifies all alerting devices.
+///
+/// Returns `None` if the responding device is not an ADS1x1x.
+/// If no device asserts the ALERT/RDY line, the read is not acknowledged
+/// and `Error::I2C` is returned.
+pub fn read_alert_response<I2C, E>(i2c: &mut I2C) -> Result<Option<SlaveAddr>, Error<E>>
+where
+    I2C: interface::I2cRead<Error = E>,
+{
+    let mut data = [0];
+    i2c.read(ALERT_RESPONSE_ADDRESS, &mut data)
+        .map_err(|e| Error::I2C(e, Operation::AlertResponse))?;
+    Ok(decode_alert_response(data[0]))
+}
+
+/// Send an SMBus alert response on an asynchronous I2C bus.
+///
+/// See [`read_alert_response()`](fn.read_alert_response.html).
+#[cfg(feature = "async")]
+pub async fn read_alert_response_async<I2C, E>(i2c: &mut I2C) -> Result<Option<SlaveAddr>, Error<E>>
+where
+    I2C: AsyncI2c<Error = E>,
+{
+    let mut data = [0];
+    i2c.read(ALERT_RESPONSE_ADDRESS, &mut data)
+        .await
+        .map_err(|e| Error::I2C(e, Operation::AlertResponse))?;
+    Ok(decode_alert_response(data[0]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_tell_resolution_from_samples() {
+        assert_eq!(
+            Some(Resolution::Bits16),
+            resolution_from_samples(&[0x1230, 0x1231])
+        );
+        assert_eq!(
+            Some(Resolution::Bits12),
+            resolution_from_samples(&[0x1230, 0x1240])
+        );
+        assert_eq!(None, resolution_from_samples(&[0, 0x8000]));
+    }
+
+    #[test]
+    fn steady_16bit_multiple_of_16_is_taken_for_12bit() {
+        assert_eq!(
+            Some(Resolution::Bits12),
+            resolution_from_samples(&[0x1230, 0x1230, 0x1230, 0x1230])
+        );
+    }
+
+    #[test]
+    fn can_tell_resolution_from_timing() {
+        assert_eq!(Resolution::Bits16, resolution_from_timing(0x05E3));
+        assert_eq!(Resolution::Bits12, resolution_from_timing(0x85E3));
+    }
+
+    #[test]
+    fn only_idle_single_shot_config_is_written_to() {
+        assert!(is_idle_single_shot(POWER_UP_CONFIG));
+        assert!(!is_idle_single_shot(POWER_UP_CONFIG & !BitFlags::OS));
+        // TMP102 power-up configuration
+        assert!(!is_idle_single_shot(0x60A0));
+    }
+
+    #[test]
+    fn only_continuous_config_without_comparator_is_sampled() {
+        assert!(is_continuous_without_comparator(
+            POWER_UP_CONFIG & !BitFlags::OP_MODE
+        ));
+        assert!(!is_continuous_without_comparator(POWER_UP_CONFIG));
+        // TMP102 power-up configuration
+        assert!(!is_continuous_without_comparator(0x60A0));
+    }
+
+    #[test]
+    fn can_decode_alert_response() {
+        assert_eq!(Some(SlaveAddr::default()), decode_alert_response(0x48 << 1));
+        assert_eq!(
+            Some(SlaveAddr::new_scl()),
+            decode_alert_response(0x4B << 1 | 1)
+        );
+        assert_eq!(None, decode_alert_response(0x50 << 1));
+    }
+}
@@ -24,7 +24,7 @@ pub mod channel {
 }
 
 /// ADC input channel selection
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ChannelSelection {
     /// Measure single-ended signal on input channel 0
     SingleA0,
@@ -44,9 +44,20 @@ pub enum ChannelSelection {
     DifferentialA2A3,
 }
 
+impl ChannelSelection {
+    /// Number of channel selections
+    pub(crate) const COUNT: usize = 8;
+
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+}
+
 macro_rules! impl_channel {
     ( $IC:ident, $CH:ident ) => {
-        impl<DI, CONV, MODE> adc::Channel<Ads1x1x<DI, ic::$IC, CONV, MODE>> for channel::$CH {
+        impl<DI, CONV, MODE, ALERT> adc::Channel<Ads1x1x<DI, ic::$IC, CONV, MODE, ALERT>>
+            for channel::$CH
+        {
             type ID = ChannelSelection;
 
             fn channel() -> Self::ID {
@@ -118,4 +129,18 @@ impl Config {
                 .with_high(BF::MUX0),
         }
     }
+
+    pub(crate) fn channel(&self) -> ChannelSelection {
+        use self::ChannelSelection as CS;
+        match (self.bits & (BF::MUX2 | BF::MUX1 | BF::MUX0)) >> 12 {
+            0b000 => CS::DifferentialA0A1,
+            0b001 => CS::DifferentialA0A3,
+            0b010 => CS::DifferentialA1A3,
+            0b011 => CS::DifferentialA2A3,
+            0b100 => CS::SingleA0,
+            0b101 => CS::SingleA1,
+            0b110 => CS::SingleA2,
+            _ => CS::SingleA3,
+        }
+    }
 }
@@ -1,35 +1,70 @@
 //! Constructor/destructor functions.
 
 use crate::{
-    ic, interface::I2cInterface, mode, Ads1x1x, Config, FullScaleRange, SlaveAddr,
-    DEVICE_BASE_ADDRESS,
+    ic,
+    interface::{I2cInterface, I2cWrite, I2cWriteRead},
+    mode, Ads1x1x, ChannelSelection, Config, FullScaleRange, SlaveAddr, DEVICE_BASE_ADDRESS,
 };
 use core::marker::PhantomData;
-use embedded_hal::blocking;
+#[cfg(feature = "async")]
+use {crate::interface::AsyncI2cInterface, embedded_hal_async::i2c::I2c as AsyncI2c};
+
+impl<DI, IC, CONV> Ads1x1x<DI, IC, CONV, mode::OneShot> {
+    pub(crate) fn create(iface: DI) -> Self {
+        Ads1x1x {
+            iface,
+            config: Config::default(),
+            fsr: FullScaleRange::default(),
+            a_conversion_was_started: false,
+            conversion_poll_limit: None,
+            conversion_polls: 0,
+            channel_profiles: [None; ChannelSelection::COUNT],
+            fresh_sample: None,
+            out_of_range_error: false,
+            _conv: PhantomData,
+            _ic: PhantomData,
+            _mode: PhantomData,
+            _alert: PhantomData,
+        }
+    }
+}
 
 macro_rules! impl_new_destroy {
-    ( $IC:ident, $create:ident, $destroy:ident, $conv:ty ) => {
+    ( $IC:ident, $create:ident, $create_async:ident, $destroy:ident, $conv:ty ) => {
         impl<I2C, E> Ads1x1x<I2cInterface<I2C>, ic::$IC, $conv, mode::OneShot>
         where
-            I2C: blocking::i2c::Write<Error = E> + blocking::i2c::WriteRead<Error = E>,
+            I2C: I2cWrite<Error = E> + I2cWriteRead<Error = E>,
         {
             /// Create a new instance of the device in OneShot mode.
             pub fn $create(i2c: I2C, address: SlaveAddr) -> Self {
-                Ads1x1x {
-                    iface: I2cInterface {
-                        i2c,
-                        address: address.addr(DEVICE_BASE_ADDRESS),
-                    },
-                    config: Config::default(),
-                    fsr: FullScaleRange::default(),
-                    a_conversion_was_started: false,
-                    _conv: PhantomData,
-                    _ic: PhantomData,
-                    _mode: PhantomData,
-                }
+                Self::create(I2cInterface {
+                    i2c,
+                    address: address.addr(DEVICE_BASE_ADDRESS),
+                })
+            }
+        }
+        impl<I2C, CONV, MODE, ALERT> Ads1x1x<I2cInterface<I2C>, ic::$IC, CONV, MODE, ALERT> {
+            /// Destroy driver instance, return I²C bus instance.
+            pub fn $destroy(self) -> I2C {
+                self.iface.i2c
+            }
+        }
+        #[cfg(feature = "async")]
+        impl<I2C> Ads1x1x<AsyncI2cInterface<I2C>, ic::$IC, $conv, mode::OneShot>
+        where
+            I2C: AsyncI2c,
+        {
+            /// Create a new instance of the device in OneShot mode using an
+            /// asynchronous I²C bus.
+            pub fn $create_async(i2c: I2C, address: SlaveAddr) -> Self {
+                Self::create(AsyncI2cInterface {
+                    i2c,
+                    address: address.addr(DEVICE_BASE_ADDRESS),
+                })
             }
         }
-        impl<I2C, CONV, MODE> Ads1x1x<I2cInterface<I2C>, ic::$IC, CONV, MODE> {
+        #[cfg(feature = "async")]
+        impl<I2C, CONV, MODE, ALERT> Ads1x1x<AsyncI2cInterface<I2C>, ic::$IC, CONV, MODE, ALERT> {
             /// Destroy driver instance, return I²C bus instance.
             pub fn $destroy(self) -> I2C {
                 self.iface.i2c
@@ -38,9 +73,45 @@ macro_rules! impl_new_destroy {
     };
 }
 
-impl_new_destroy!(Ads1013, new_ads1013, destroy_ads1013, ic::Resolution12Bit);
-impl_new_destroy!(Ads1113, new_ads1113, destroy_ads1113, ic::Resolution16Bit);
-impl_new_destroy!(Ads1014, new_ads1014, destroy_ads1014, ic::Resolution12Bit);
-impl_new_destroy!(Ads1114, new_ads1114, destroy_ads1114, ic::Resolution16Bit);
-impl_new_destroy!(Ads1015, new_ads1015, destroy_ads1015, ic::Resolution12Bit);
-impl_new_destroy!(Ads1115, new_ads1115, destroy_ads1115, ic::Resolution16Bit);
+impl_new_destroy!(
+    Ads1013,
+    new_ads1013,
+    new_ads1013_async,
+    destroy_ads1013,
+    ic::Resolution12Bit
+);
+impl_new_destroy!(
+    Ads1113,
+    new_ads1113,
+    new_ads1113_async,
+    destroy_ads1113,
+    ic::Resolution16Bit
+);
+impl_new_destroy!(
+    Ads1014,
+    new_ads1014,
+    new_ads1014_async,
+    destroy_ads1014,
+    ic::Resolution12Bit
+);
+impl_new_destroy!(
+    Ads1114,
+    new_ads1114,
+    new_ads1114_async,
+    destroy_ads1114,
+    ic::Resolution16Bit
+);
+impl_new_destroy!(
+    Ads1015,
+    new_ads1015,
+    new_ads1015_async,
+    destroy_ads1015,
+    ic::Resolution12Bit
+);
+impl_new_destroy!(
+    Ads1115,
+    new_ads1115,
+    new_ads1115_async,
+    destroy_ads1115,
+    ic::Resolution16Bit
+);
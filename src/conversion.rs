@@ -1,28 +1,39 @@
-use crate::{ic, private, Error};
+use crate::{ic, private, Clipping, Error, FullScaleRange};
 
 #[doc(hidden)]
 pub trait ConvertThreshold<E>: private::Sealed {
     fn convert_threshold(value: i16) -> Result<u16, Error<E>>;
+    fn convert_threshold_register(register_data: u16) -> i16;
 }
 
 impl<E> ConvertThreshold<E> for ic::Resolution12Bit {
+    #[allow(clippy::manual_range_contains)]
     fn convert_threshold(value: i16) -> Result<u16, Error<E>> {
         if value < -2048 || value > 2047 {
             return Err(Error::InvalidInputData);
         }
         Ok((value << 4) as u16)
     }
+
+    fn convert_threshold_register(register_data: u16) -> i16 {
+        (register_data as i16) >> 4
+    }
 }
 
 impl<E> ConvertThreshold<E> for ic::Resolution16Bit {
     fn convert_threshold(value: i16) -> Result<u16, Error<E>> {
         Ok(value as u16)
     }
+
+    fn convert_threshold_register(register_data: u16) -> i16 {
+        register_data as i16
+    }
 }
 
 #[doc(hidden)]
 pub trait ConvertMeasurement: private::Sealed {
     fn convert_measurement(register_data: u16) -> i16;
+    fn clipping(value: i16) -> Option<Clipping>;
 }
 
 impl ConvertMeasurement for ic::Resolution12Bit {
@@ -36,18 +47,91 @@ impl ConvertMeasurement for ic::Resolution12Bit {
             (value >> 4) as i16
         }
     }
+
+    fn clipping(value: i16) -> Option<Clipping> {
+        if value >= 2047 {
+            Some(Clipping::Positive)
+        } else if value <= -2048 {
+            Some(Clipping::Negative)
+        } else {
+            None
+        }
+    }
 }
 
 impl ConvertMeasurement for ic::Resolution16Bit {
     fn convert_measurement(register_data: u16) -> i16 {
         register_data as i16
     }
+
+    fn clipping(value: i16) -> Option<Clipping> {
+        match value {
+            i16::MAX => Some(Clipping::Positive),
+            i16::MIN => Some(Clipping::Negative),
+            _ => None,
+        }
+    }
+}
+
+/// Convert a measurement into volts using the given full-scale range.
+pub(crate) fn convert_to_volts<CONV: ic::Resolution>(value: i16, fsr: FullScaleRange) -> f32 {
+    f32::from(value) * fsr.millivolts() as f32 / (1000.0 * CONV::FULL_SCALE_CODE as f32)
+}
+
+/// Convert a measurement into millivolts using the given full-scale range.
+///
+/// The result is rounded to the nearest millivolt.
+pub(crate) fn convert_to_millivolts<CONV: ic::Resolution>(value: i16, fsr: FullScaleRange) -> i32 {
+    let numerator = 2 * i32::from(value) * fsr.millivolts();
+    let denominator = 2 * CONV::FULL_SCALE_CODE;
+    if numerator < 0 {
+        (numerator - CONV::FULL_SCALE_CODE) / denominator
+    } else {
+        (numerator + CONV::FULL_SCALE_CODE) / denominator
+    }
+}
+
+/// Convert a voltage into the corresponding output code using the given full-scale range.
+///
+/// The result is rounded to the nearest code. Returns `Error::InvalidInputData`
+/// if the voltage is outside the full-scale range or not a number.
+pub(crate) fn convert_from_volts<CONV: ic::Resolution, E>(
+    volts: f32,
+    fsr: FullScaleRange,
+) -> Result<i16, Error<E>> {
+    let code = volts * 1000.0 * CONV::FULL_SCALE_CODE as f32 / fsr.millivolts() as f32;
+    if !code.is_finite() {
+        return Err(Error::InvalidInputData);
+    }
+    let code = if code < 0.0 { code - 0.5 } else { code + 0.5 } as i32;
+    if code < -CONV::FULL_SCALE_CODE || code >= CONV::FULL_SCALE_CODE {
+        return Err(Error::InvalidInputData);
+    }
+    Ok(code as i16)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn can_detect_clipping_12_bit() {
+        use ic::Resolution12Bit as R;
+        assert_eq!(Some(Clipping::Positive), R::clipping(2047));
+        assert_eq!(None, R::clipping(2046));
+        assert_eq!(None, R::clipping(-2047));
+        assert_eq!(Some(Clipping::Negative), R::clipping(-2048));
+    }
+
+    #[test]
+    fn can_detect_clipping_16_bit() {
+        use ic::Resolution16Bit as R;
+        assert_eq!(Some(Clipping::Positive), R::clipping(32767));
+        assert_eq!(None, R::clipping(32766));
+        assert_eq!(None, R::clipping(-32767));
+        assert_eq!(Some(Clipping::Negative), R::clipping(-32768));
+    }
+
     #[test]
     fn convert_measurement_12_bits() {
         assert_eq!(0, ic::Resolution12Bit::convert_measurement(0));
@@ -101,4 +185,134 @@ mod tests {
         assert_eq!(0x7FFF, convert_threshold::<ic::Resolution16Bit>(32767));
         assert_eq!(0x8000, convert_threshold::<ic::Resolution16Bit>(-32768));
     }
+
+    #[test]
+    fn convert_threshold_register_12_bits() {
+        for value in [0, 1, 2047, -1, -2048] {
+            let register_data = convert_threshold::<ic::Resolution12Bit>(value);
+            assert_eq!(
+                value,
+                <ic::Resolution12Bit as ConvertThreshold<()>>::convert_threshold_register(
+                    register_data
+                )
+            );
+        }
+        assert_eq!(
+            2047,
+            <ic::Resolution12Bit as ConvertThreshold<()>>::convert_threshold_register(0x7FFF)
+        );
+    }
+
+    #[test]
+    fn convert_threshold_register_16_bits() {
+        assert_eq!(
+            -32768,
+            <ic::Resolution16Bit as ConvertThreshold<()>>::convert_threshold_register(0x8000)
+        );
+        assert_eq!(
+            32767,
+            <ic::Resolution16Bit as ConvertThreshold<()>>::convert_threshold_register(0x7FFF)
+        );
+    }
+
+    #[test]
+    fn convert_to_volts_12_bits() {
+        let fsr = FullScaleRange::Within2_048V;
+        assert_eq!(0.0, convert_to_volts::<ic::Resolution12Bit>(0, fsr));
+        assert_eq!(-2.048, convert_to_volts::<ic::Resolution12Bit>(-2048, fsr));
+        assert_eq!(1.024, convert_to_volts::<ic::Resolution12Bit>(1024, fsr));
+        let fsr = FullScaleRange::Within6_144V;
+        assert_eq!(0.003, convert_to_volts::<ic::Resolution12Bit>(1, fsr));
+    }
+
+    #[test]
+    fn convert_to_volts_16_bits() {
+        let fsr = FullScaleRange::Within0_256V;
+        assert_eq!(-0.256, convert_to_volts::<ic::Resolution16Bit>(-32768, fsr));
+        assert_eq!(0.128, convert_to_volts::<ic::Resolution16Bit>(16384, fsr));
+        let fsr = FullScaleRange::Within4_096V;
+        assert_eq!(0.000125, convert_to_volts::<ic::Resolution16Bit>(1, fsr));
+    }
+
+    #[test]
+    fn convert_to_millivolts_12_bits() {
+        let fsr = FullScaleRange::Within2_048V;
+        assert_eq!(0, convert_to_millivolts::<ic::Resolution12Bit>(0, fsr));
+        assert_eq!(
+            2047,
+            convert_to_millivolts::<ic::Resolution12Bit>(2047, fsr)
+        );
+        assert_eq!(
+            -2048,
+            convert_to_millivolts::<ic::Resolution12Bit>(-2048, fsr)
+        );
+        let fsr = FullScaleRange::Within0_256V;
+        assert_eq!(1, convert_to_millivolts::<ic::Resolution12Bit>(11, fsr));
+        assert_eq!(2, convert_to_millivolts::<ic::Resolution12Bit>(12, fsr));
+        assert_eq!(-2, convert_to_millivolts::<ic::Resolution12Bit>(-12, fsr));
+    }
+
+    #[test]
+    fn convert_to_millivolts_16_bits() {
+        let fsr = FullScaleRange::Within6_144V;
+        assert_eq!(
+            6144,
+            convert_to_millivolts::<ic::Resolution16Bit>(32767, fsr)
+        );
+        assert_eq!(
+            -6144,
+            convert_to_millivolts::<ic::Resolution16Bit>(-32768, fsr)
+        );
+        let fsr = FullScaleRange::Within0_256V;
+        assert_eq!(0, convert_to_millivolts::<ic::Resolution16Bit>(63, fsr));
+        assert_eq!(1, convert_to_millivolts::<ic::Resolution16Bit>(64, fsr));
+        assert_eq!(-1, convert_to_millivolts::<ic::Resolution16Bit>(-64, fsr));
+    }
+
+    fn convert_from_volts<T: ic::Resolution>(volts: f32, fsr: FullScaleRange) -> i16 {
+        super::convert_from_volts::<T, ()>(volts, fsr).unwrap()
+    }
+
+    fn assert_invalid_volts<T: ic::Resolution>(volts: f32, fsr: FullScaleRange) {
+        match super::convert_from_volts::<T, ()>(volts, fsr) {
+            Err(Error::InvalidInputData) => (),
+            _ => panic!("InvalidInputData error was not returned."),
+        }
+    }
+
+    #[test]
+    fn convert_from_volts_12_bits() {
+        let fsr = FullScaleRange::Within2_048V;
+        assert_eq!(0, convert_from_volts::<ic::Resolution12Bit>(0.0, fsr));
+        assert_eq!(1500, convert_from_volts::<ic::Resolution12Bit>(1.5, fsr));
+        assert_eq!(-1500, convert_from_volts::<ic::Resolution12Bit>(-1.5, fsr));
+        assert_eq!(2047, convert_from_volts::<ic::Resolution12Bit>(2.047, fsr));
+        assert_eq!(
+            -2048,
+            convert_from_volts::<ic::Resolution12Bit>(-2.048, fsr)
+        );
+        assert_invalid_volts::<ic::Resolution12Bit>(2.048, fsr);
+        assert_invalid_volts::<ic::Resolution12Bit>(-2.049, fsr);
+        let fsr = FullScaleRange::Within6_144V;
+        assert_eq!(1, convert_from_volts::<ic::Resolution12Bit>(0.0015, fsr));
+        assert_eq!(-1, convert_from_volts::<ic::Resolution12Bit>(-0.0015, fsr));
+        assert_eq!(0, convert_from_volts::<ic::Resolution12Bit>(0.0014, fsr));
+    }
+
+    #[test]
+    fn convert_from_volts_16_bits() {
+        let fsr = FullScaleRange::Within4_096V;
+        assert_eq!(8000, convert_from_volts::<ic::Resolution16Bit>(1.0, fsr));
+        assert_eq!(
+            32767,
+            convert_from_volts::<ic::Resolution16Bit>(4.0959, fsr)
+        );
+        assert_eq!(
+            -32768,
+            convert_from_volts::<ic::Resolution16Bit>(-4.096, fsr)
+        );
+        assert_invalid_volts::<ic::Resolution16Bit>(4.096, fsr);
+        assert_invalid_volts::<ic::Resolution16Bit>(f32::NAN, fsr);
+        assert_invalid_volts::<ic::Resolution16Bit>(f32::INFINITY, fsr);
+    }
 }
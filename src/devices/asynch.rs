@@ -0,0 +1,894 @@
+//! Asynchronous device functions
+//!
+//! These mirror the blocking functions and share the configuration register
+//! logic with them.
+
+use crate::{
+    alert,
+    channels::ChannelSelection,
+    conversion, ic,
+    interface::AsyncI2cInterface,
+    mode,
+    stream::{Sample, SampleSink, Stream},
+    Ads1x1x, AutoRange, AutoRangeMeasurement, BitFlags as BF, ChannelScan, ComparatorLatching,
+    ComparatorMode, ComparatorPolarity, ComparatorQueue, Config, ConfigBuilder, Configuration,
+    DataRate12Bit, DataRate16Bit, Error, FullScaleRange, Measurement, ModeChangeError,
+    OperatingMode, Register, Sequence, SequenceStep, SlaveAddr, CONVERSION_POLL_RETRIES,
+};
+use core::{
+    future::{poll_fn, Future},
+    pin::pin,
+    task::Poll,
+};
+use embedded_hal::adc;
+use embedded_hal_async::{delay::DelayNs, digital::Wait, i2c::I2c};
+
+/// Lower bound of the time polling the configuration register takes in microseconds.
+///
+/// Reading a register transfers four bytes, which takes longer than this even
+/// in I2C high-speed mode.
+const MIN_POLL_DURATION_US: u32 = 10;
+
+impl<I2C, IC, CONV, MODE, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, ALERT>
+where
+    I2C: I2c<Error = E>,
+{
+    async fn write_config(&mut self, config: Config) -> Result<(), Error<E>> {
+        self.iface
+            .write_register(Register::CONFIG, config.bits)
+            .await?;
+        self.config = config;
+        Ok(())
+    }
+
+    async fn set_operating_mode(&mut self, mode: OperatingMode) -> Result<(), Error<E>> {
+        self.write_config(self.config.with_operating_mode(mode))
+            .await
+    }
+
+    /// Change several settings with a single write to the configuration register.
+    ///
+    /// See [`configure()`](struct.Ads1x1x.html#method.configure).
+    pub async fn configure<F>(&mut self, f: F) -> Result<(), Error<E>>
+    where
+        IC: ic::Features,
+        F: FnOnce(ConfigBuilder<IC, CONV, ALERT>) -> ConfigBuilder<IC, CONV, ALERT>,
+    {
+        let config = self.config_builder(f);
+        self.write_config(config).await?;
+        if IC::TIER2 {
+            self.fsr = self.config.full_scale_range();
+        }
+        Ok(())
+    }
+
+    /// Read whether a measurement is currently in progress.
+    pub async fn is_measurement_in_progress(&mut self) -> Result<bool, Error<E>> {
+        let config = Config {
+            bits: self.iface.read_register(Register::CONFIG).await?,
+        };
+        Ok(!config.is_high(BF::OS))
+    }
+
+    /// Reset the device through an I2C general-call Reset command.
+    ///
+    /// See [`general_call_reset()`](struct.Ads1x1x.html#method.general_call_reset).
+    pub async fn general_call_reset(
+        mut self,
+    ) -> Result<
+        Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, alert::Disabled>,
+        ModeChangeError<E, Self>,
+    > {
+        if let Err(Error::I2C(e, _)) = self.iface.write_general_call_reset().await {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        self.config = Config::default();
+        self.fsr = FullScaleRange::default();
+        Ok(self.into_mode(false).into_alert())
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, ALERT>
+where
+    I2C: I2c<Error = E>,
+    CONV: ic::Resolution,
+{
+    /// Read the configuration register of the device and decode it.
+    ///
+    /// See [`read_configuration()`](struct.Ads1x1x.html#method.read_configuration).
+    pub async fn read_configuration(&mut self) -> Result<Configuration<CONV::DataRate>, Error<E>> {
+        let config = Config {
+            bits: self.iface.read_register(Register::CONFIG).await?,
+        };
+        Ok(config.decode::<CONV>())
+    }
+
+    /// Take over the configuration of the device.
+    ///
+    /// See [`sync_from_device()`](struct.Ads1x1x.html#method.sync_from_device).
+    pub async fn sync_from_device(&mut self) -> Result<Configuration<CONV::DataRate>, Error<E>>
+    where
+        IC: ic::Features,
+        ALERT: alert::Role,
+    {
+        let device_config = Config {
+            bits: self.iface.read_register(Register::CONFIG).await?,
+        };
+        self.take_over_config(&device_config)?;
+        Ok(device_config.decode::<CONV>())
+    }
+}
+
+impl<I2C, IC, MODE, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, ic::Resolution12Bit, MODE, ALERT>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Set data rate
+    pub async fn set_data_rate(&mut self, rate: DataRate12Bit) -> Result<(), Error<E>> {
+        self.write_config(self.config.with_data_rate_12bit(rate))
+            .await
+    }
+}
+
+impl<I2C, IC, MODE, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, ic::Resolution16Bit, MODE, ALERT>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Set data rate
+    pub async fn set_data_rate(&mut self, rate: DataRate16Bit) -> Result<(), Error<E>> {
+        self.write_config(self.config.with_data_rate_16bit(rate))
+            .await
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, ALERT>
+where
+    I2C: I2c<Error = E>,
+    IC: ic::Tier2Features,
+{
+    /// Set the input voltage measurable range
+    ///
+    /// This configures the programmable gain amplifier and determines the measurable input voltage range.
+    pub async fn set_full_scale_range(&mut self, range: FullScaleRange) -> Result<(), Error<E>> {
+        self.write_config(self.config.with_full_scale_range(range))
+            .await?;
+        self.fsr = range;
+        Ok(())
+    }
+
+    /// Set comparator mode
+    pub async fn set_comparator_mode(&mut self, mode: ComparatorMode) -> Result<(), Error<E>> {
+        self.write_config(self.config.with_comparator_mode(mode))
+            .await
+    }
+
+    /// Set comparator polarity
+    pub async fn set_comparator_polarity(
+        &mut self,
+        polarity: ComparatorPolarity,
+    ) -> Result<(), Error<E>> {
+        self.write_config(self.config.with_comparator_polarity(polarity))
+            .await
+    }
+
+    /// Set comparator latching
+    pub async fn set_comparator_latching(
+        &mut self,
+        latching: ComparatorLatching,
+    ) -> Result<(), Error<E>> {
+        self.write_config(self.config.with_comparator_latching(latching))
+            .await
+    }
+
+    /// Write the configuration and change the role of the ALERT/RDY pin.
+    async fn change_alert_role<NEWALERT>(
+        mut self,
+        config: Config,
+        ready_thresholds: bool,
+    ) -> Result<Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, NEWALERT>, ModeChangeError<E, Self>>
+    {
+        for (register, value) in Self::alert_role_writes(&config, ready_thresholds) {
+            if let Err(Error::I2C(e, _)) = self.iface.write_register(register, value).await {
+                return Err(ModeChangeError::I2C(e, self));
+            }
+        }
+        self.config = config;
+        Ok(self.into_alert())
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, ALERT>
+where
+    I2C: I2c<Error = E>,
+    IC: ic::Tier2Features,
+    CONV: conversion::ConvertThreshold<E> + ic::Resolution,
+{
+    /// Read raw comparator lower threshold
+    ///
+    /// See [`low_threshold_raw()`](struct.Ads1x1x.html#method.low_threshold_raw).
+    pub async fn low_threshold_raw(&mut self) -> Result<i16, Error<E>> {
+        let register_value = self.iface.read_register(Register::LOW_TH).await?;
+        Ok(CONV::convert_threshold_register(register_value))
+    }
+
+    /// Read raw comparator upper threshold
+    ///
+    /// See [`high_threshold_raw()`](struct.Ads1x1x.html#method.high_threshold_raw).
+    pub async fn high_threshold_raw(&mut self) -> Result<i16, Error<E>> {
+        let register_value = self.iface.read_register(Register::HIGH_TH).await?;
+        Ok(CONV::convert_threshold_register(register_value))
+    }
+
+    /// Read comparator lower threshold in volts
+    ///
+    /// See [`low_threshold_voltage()`](struct.Ads1x1x.html#method.low_threshold_voltage).
+    pub async fn low_threshold_voltage(&mut self) -> Result<f32, Error<E>> {
+        let value = self.low_threshold_raw().await?;
+        Ok(conversion::convert_to_volts::<CONV>(value, self.fsr))
+    }
+
+    /// Read comparator upper threshold in volts
+    ///
+    /// See [`high_threshold_voltage()`](struct.Ads1x1x.html#method.high_threshold_voltage).
+    pub async fn high_threshold_voltage(&mut self) -> Result<f32, Error<E>> {
+        let value = self.high_threshold_raw().await?;
+        Ok(conversion::convert_to_volts::<CONV>(value, self.fsr))
+    }
+}
+
+impl<I2C, IC, CONV, MODE, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, ALERT>
+where
+    I2C: I2c<Error = E>,
+    IC: ic::Tier2Features,
+    CONV: conversion::ConvertThreshold<E> + ic::Resolution,
+    ALERT: alert::ConfigurableThresholds,
+{
+    /// Set raw comparator lower threshold
+    ///
+    /// See [`set_low_threshold_raw()`](struct.Ads1x1x.html#method.set_low_threshold_raw).
+    pub async fn set_low_threshold_raw(&mut self, value: i16) -> Result<(), Error<E>> {
+        let register_value = CONV::convert_threshold(value)?;
+        self.iface
+            .write_register(Register::LOW_TH, register_value)
+            .await
+    }
+
+    /// Set raw comparator upper threshold
+    ///
+    /// See [`set_high_threshold_raw()`](struct.Ads1x1x.html#method.set_high_threshold_raw).
+    pub async fn set_high_threshold_raw(&mut self, value: i16) -> Result<(), Error<E>> {
+        let register_value = CONV::convert_threshold(value)?;
+        self.iface
+            .write_register(Register::HIGH_TH, register_value)
+            .await
+    }
+
+    /// Set comparator lower threshold in volts
+    ///
+    /// See [`set_low_threshold_voltage()`](struct.Ads1x1x.html#method.set_low_threshold_voltage).
+    pub async fn set_low_threshold_voltage(&mut self, volts: f32) -> Result<(), Error<E>> {
+        let value = conversion::convert_from_volts::<CONV, E>(volts, self.fsr)?;
+        self.set_low_threshold_raw(value).await
+    }
+
+    /// Set comparator upper threshold in volts
+    ///
+    /// See [`set_high_threshold_voltage()`](struct.Ads1x1x.html#method.set_high_threshold_voltage).
+    pub async fn set_high_threshold_voltage(&mut self, volts: f32) -> Result<(), Error<E>> {
+        let value = conversion::convert_from_volts::<CONV, E>(volts, self.fsr)?;
+        self.set_high_threshold_raw(value).await
+    }
+
+    /// Set comparator lower and upper thresholds in volts
+    ///
+    /// See [`set_threshold_window_voltage()`](struct.Ads1x1x.html#method.set_threshold_window_voltage).
+    pub async fn set_threshold_window_voltage(
+        &mut self,
+        low: f32,
+        high: f32,
+    ) -> Result<(), Error<E>> {
+        let (low, high) = self.threshold_window(low, high)?;
+        self.set_low_threshold_raw(low).await?;
+        self.set_high_threshold_raw(high).await
+    }
+}
+
+impl<I2C, IC, CONV, MODE, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, alert::Disabled>
+where
+    I2C: I2c<Error = E>,
+    IC: ic::Tier2Features,
+{
+    /// Activate the comparator with the given alert queue
+    ///
+    /// See [`enable_comparator()`](struct.Ads1x1x.html#method.enable_comparator).
+    pub async fn enable_comparator(
+        self,
+        queue: ComparatorQueue,
+    ) -> Result<
+        Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, alert::Comparator>,
+        ModeChangeError<E, Self>,
+    > {
+        let config = self.config.with_comparator_queue(queue);
+        self.change_alert_role(config, false).await
+    }
+
+    /// Use the ALERT/RDY pin as conversion-ready pin.
+    ///
+    /// See [`use_alert_rdy_pin_as_ready()`](struct.Ads1x1x.html#method.use_alert_rdy_pin_as_ready).
+    pub async fn use_alert_rdy_pin_as_ready(
+        self,
+    ) -> Result<
+        Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, alert::ConversionReady>,
+        ModeChangeError<E, Self>,
+    > {
+        let config = self.config.with_comparator_queue(ComparatorQueue::One);
+        self.change_alert_role(config, true).await
+    }
+}
+
+impl<I2C, IC, CONV, MODE, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, alert::Comparator>
+where
+    I2C: I2c<Error = E>,
+    IC: ic::Tier2Features,
+{
+    /// Send an SMBus alert response and return the address of the device
+    /// which asserted the ALERT/RDY line.
+    ///
+    /// See [`read_alert_response()`](struct.Ads1x1x.html#method.read_alert_response).
+    pub async fn read_alert_response(&mut self) -> Result<Option<SlaveAddr>, Error<E>> {
+        crate::bus::read_alert_response_async(&mut self.iface.i2c).await
+    }
+
+    /// Set the comparator alert queue
+    pub async fn set_comparator_queue(&mut self, queue: ComparatorQueue) -> Result<(), Error<E>> {
+        self.write_config(self.config.with_comparator_queue(queue))
+            .await
+    }
+
+    /// Use the ALERT/RDY pin as conversion-ready pin.
+    ///
+    /// See [`use_alert_rdy_pin_as_ready()`](struct.Ads1x1x.html#method.use_alert_rdy_pin_as_ready).
+    pub async fn use_alert_rdy_pin_as_ready(
+        self,
+    ) -> Result<
+        Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, alert::ConversionReady>,
+        ModeChangeError<E, Self>,
+    > {
+        let config = self.config.clone();
+        self.change_alert_role(config, true).await
+    }
+
+    /// Disable comparator (default)
+    ///
+    /// See [`disable_comparator()`](struct.Ads1x1x.html#method.disable_comparator).
+    pub async fn disable_comparator(
+        self,
+    ) -> Result<
+        Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, alert::Disabled>,
+        ModeChangeError<E, Self>,
+    > {
+        let config = self.config.with_comparator_disabled();
+        self.change_alert_role(config, false).await
+    }
+}
+
+impl<I2C, IC, CONV, MODE, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, alert::ConversionReady>
+where
+    I2C: I2c<Error = E>,
+    IC: ic::Tier2Features,
+{
+    /// Disable comparator and conversion-ready pin (default)
+    ///
+    /// See [`disable_comparator()`](struct.Ads1x1x.html#method.disable_comparator).
+    pub async fn disable_comparator(
+        self,
+    ) -> Result<
+        Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, MODE, alert::Disabled>,
+        ModeChangeError<E, Self>,
+    > {
+        let config = self.config.with_comparator_disabled();
+        self.change_alert_role(config, false).await
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>
+where
+    I2C: I2c<Error = E>,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Change operating mode to Continuous
+    pub async fn into_continuous(
+        mut self,
+    ) -> Result<
+        Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::Continuous, ALERT>,
+        ModeChangeError<E, Self>,
+    > {
+        if let Err(Error::I2C(e, _)) = self.set_operating_mode(OperatingMode::Continuous).await {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        Ok(self.into_mode(true))
+    }
+
+    /// Make a measurement on the specified channel.
+    ///
+    /// This triggers a conversion and polls the device until it has finished
+    /// or the configured poll limit is reached.
+    /// Unless a poll limit is set, `Error::Timeout` is returned once polling
+    /// has taken longer than the conversion time of the configured data rate.
+    /// In that case a new conversion is triggered.
+    /// The output value will be within `[2047..-2048]` for 12-bit devices
+    /// (`ADS101x`) and within `[32767..-32768]` for 16-bit devices (`ADS111x`).
+    ///
+    /// See [`set_conversion_poll_limit()`](struct.Ads1x1x.html#method.set_conversion_poll_limit).
+    pub async fn read<CH>(&mut self, _channel: &mut CH) -> Result<i16, Error<E>>
+    where
+        CH: adc::Channel<
+            Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>,
+            ID = ChannelSelection,
+        >,
+    {
+        self.start_conversion(CH::channel()).await?;
+        self.wait_for_conversion().await?;
+        self.read_conversion().await
+    }
+
+    /// Make a measurement on the specified channel waiting for the conversion
+    /// to finish using the provided delay.
+    ///
+    /// This waits for the conversion time corresponding to the configured
+    /// data rate before reading the result, which avoids polling the device
+    /// while the conversion is ongoing.
+    ///
+    /// See [`read_blocking()`](struct.Ads1x1x.html#method.read_blocking).
+    pub async fn read_with_delay<CH, D>(
+        &mut self,
+        _channel: &mut CH,
+        delay: &mut D,
+    ) -> Result<i16, Error<E>>
+    where
+        CH: adc::Channel<
+            Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>,
+            ID = ChannelSelection,
+        >,
+        D: DelayNs,
+    {
+        self.start_conversion(CH::channel()).await?;
+        let conversion_time_us = self.config.conversion_time_us::<CONV>();
+        delay.delay_us(conversion_time_us).await;
+        self.poll_with_delay(delay).await?;
+        self.read_conversion().await
+    }
+
+    /// Poll the device until the conversion has finished waiting in between
+    /// polls using the delay.
+    ///
+    /// Unless a poll limit is set, this gives up with `Error::Timeout` after
+    /// the default number of retries.
+    async fn poll_with_delay<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<E>> {
+        let conversion_time_us = self.config.conversion_time_us::<CONV>();
+        let limit = self
+            .conversion_poll_limit
+            .unwrap_or(CONVERSION_POLL_RETRIES);
+        while self.is_conversion_in_progress().await? {
+            self.check_poll_limit(Some(limit)).await?;
+            delay.delay_us(conversion_time_us / 8 + 1).await;
+        }
+        Ok(())
+    }
+
+    /// Make a measurement on the specified channel and return the result in volts.
+    ///
+    /// See [`read_voltage()`](struct.Ads1x1x.html#method.read_voltage).
+    pub async fn read_voltage<CH>(&mut self, channel: &mut CH) -> Result<f32, Error<E>>
+    where
+        CH: adc::Channel<
+            Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>,
+            ID = ChannelSelection,
+        >,
+    {
+        let value = self.read(channel).await?;
+        Ok(conversion::convert_to_volts::<CONV>(value, self.fsr))
+    }
+
+    /// Make a measurement on the specified channel and return the result in
+    /// millivolts, rounded to the nearest millivolt.
+    ///
+    /// See [`read_millivolts()`](struct.Ads1x1x.html#method.read_millivolts).
+    pub async fn read_millivolts<CH>(&mut self, channel: &mut CH) -> Result<i32, Error<E>>
+    where
+        CH: adc::Channel<
+            Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>,
+            ID = ChannelSelection,
+        >,
+    {
+        let value = self.read(channel).await?;
+        Ok(conversion::convert_to_millivolts::<CONV>(value, self.fsr))
+    }
+
+    /// Make a measurement on the specified channel and return the result
+    /// flagged with whether it was clipped.
+    ///
+    /// See [`read_measurement()`](struct.Ads1x1x.html#method.read_measurement).
+    pub async fn read_measurement<CH>(&mut self, channel: &mut CH) -> Result<Measurement, Error<E>>
+    where
+        CH: adc::Channel<
+            Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>,
+            ID = ChannelSelection,
+        >,
+    {
+        let value = self.read(channel).await?;
+        Ok(Self::flagged_measurement(value))
+    }
+
+    async fn start_conversion(&mut self, channel: ChannelSelection) -> Result<(), Error<E>> {
+        self.finish_cancelled_conversion().await?;
+        let (config, fsr) = self.channel_config(channel);
+        self.start_measurement(config, fsr).await
+    }
+
+    /// Wait for the end of a conversion which may still be in progress.
+    async fn finish_cancelled_conversion(&mut self) -> Result<(), Error<E>> {
+        // A conversion started by a cancelled read may still be in progress.
+        // Writing the OS bit has no effect until it is finished.
+        self.wait_for_conversion().await
+    }
+
+    async fn start_measurement(
+        &mut self,
+        config: Config,
+        fsr: FullScaleRange,
+    ) -> Result<(), Error<E>> {
+        self.trigger_measurement(&config).await?;
+        self.config = config;
+        self.fsr = fsr;
+        self.a_conversion_was_started = true;
+        Ok(())
+    }
+
+    /// Poll the device back-to-back until the conversion has finished.
+    ///
+    /// Unless a poll limit is set, this gives up with `Error::Timeout` once
+    /// polling has taken longer than the conversion time plus the default
+    /// number of retries.
+    async fn wait_for_conversion(&mut self) -> Result<(), Error<E>> {
+        let limit = self.conversion_poll_limit.unwrap_or_else(|| {
+            let conversion_time_us = self.config.conversion_time_us::<CONV>();
+            CONVERSION_POLL_RETRIES + conversion_time_us / MIN_POLL_DURATION_US
+        });
+        while self.is_conversion_in_progress().await? {
+            self.check_poll_limit(Some(limit)).await?;
+        }
+        Ok(())
+    }
+
+    async fn trigger_measurement(&mut self, config: &Config) -> Result<(), Error<E>> {
+        let bits = self.trigger_bits(config);
+        self.iface.write_register(Register::CONFIG, bits).await
+    }
+
+    /// See the blocking version of `is_conversion_in_progress()`.
+    async fn is_conversion_in_progress(&mut self) -> Result<bool, Error<E>> {
+        let device_config = Config {
+            bits: self.iface.read_register(Register::CONFIG).await?,
+        };
+        let status = self.conversion_status(&device_config);
+        self.retrigger_on_error(status).await
+    }
+
+    /// See the blocking version of `check_poll_limit()`.
+    async fn check_poll_limit(&mut self, limit: Option<u32>) -> Result<(), Error<E>> {
+        let result = self.count_poll(limit);
+        self.retrigger_on_error(result).await
+    }
+
+    /// See the blocking version of `retrigger_on_error()`.
+    async fn retrigger_on_error<T>(&mut self, result: Result<T, Error<E>>) -> Result<T, Error<E>> {
+        if result.is_err() {
+            let config = self.config.clone();
+            self.trigger_measurement(&config).await?;
+            self.a_conversion_was_started = true;
+        }
+        result
+    }
+
+    async fn read_conversion(&mut self) -> Result<i16, Error<E>> {
+        let value = self.read_conversion_unchecked().await?;
+        self.checked_measurement(value)
+    }
+
+    /// Read the conversion result regardless of whether clipped
+    /// measurements are returned as errors.
+    async fn read_conversion_unchecked(&mut self) -> Result<i16, Error<E>> {
+        let value = self.iface.read_register(Register::CONVERSION).await?;
+        self.a_conversion_was_started = false;
+        Ok(CONV::convert_measurement(value))
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>
+where
+    I2C: I2c<Error = E>,
+    IC: ic::Features,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Measure a sequence of channels back-to-back, each optionally with its
+    /// own full-scale range.
+    ///
+    /// See [`read_sequence()`](struct.Ads1x1x.html#method.read_sequence).
+    pub async fn read_sequence<const N: usize>(
+        &mut self,
+        sequence: &Sequence<N>,
+    ) -> Result<[i16; N], Error<E>> {
+        self.finish_cancelled_conversion().await?;
+        let mut results = [0; N];
+        for (result, step) in results.iter_mut().zip(sequence.steps.iter()) {
+            let (config, fsr) = self.sequence_step_config(step)?;
+            self.start_measurement(config, fsr).await?;
+            self.wait_for_conversion().await?;
+            *result = self.read_conversion().await?;
+        }
+        Ok(results)
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>
+where
+    I2C: I2c<Error = E>,
+    IC: ic::Tier2Features + ic::Features,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Make a measurement on the specified channel choosing the full-scale
+    /// range automatically.
+    ///
+    /// See [`read_auto_range()`](struct.Ads1x1x.html#method.read_auto_range).
+    pub async fn read_auto_range<CH>(
+        &mut self,
+        _channel: &mut CH,
+        auto_range: &mut AutoRange,
+    ) -> Result<AutoRangeMeasurement, Error<E>>
+    where
+        CH: adc::Channel<
+            Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>,
+            ID = ChannelSelection,
+        >,
+    {
+        self.finish_cancelled_conversion().await?;
+        let mut previous = None;
+        loop {
+            let step =
+                SequenceStep::new(CH::channel()).with_full_scale_range(auto_range.full_scale_range);
+            let (config, fsr) = self.sequence_step_config(&step)?;
+            self.start_measurement(config, fsr).await?;
+            self.wait_for_conversion().await?;
+            let value = self.read_conversion_unchecked().await?;
+            match auto_range.next_step::<CONV>(value, previous) {
+                Some(step) => {
+                    auto_range.take_step(step);
+                    previous = Some(step);
+                }
+                None => {
+                    let value = self.checked_measurement(value)?;
+                    return Ok(auto_range.measurement::<CONV>(value));
+                }
+            }
+        }
+    }
+}
+
+impl<I2C, IC, CONV, E>
+    Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, alert::ConversionReady>
+where
+    I2C: I2c<Error = E>,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Make a measurement on the specified channel waiting for the end of the
+    /// conversion on the ALERT/RDY pin.
+    ///
+    /// `pin` must be an input connected to the ALERT/RDY pin of the device.
+    /// The configuration register is not polled while waiting for the pin.
+    ///
+    /// If the pin does not signal the end of the conversion within the
+    /// conversion time of the configured data rate, the configuration
+    /// register is polled using the delay like in
+    /// [`read_with_delay()`](struct.Ads1x1x.html#method.read_with_delay),
+    /// which returns `Error::Timeout` once the poll limit is reached.
+    pub async fn read_with_ready_pin<CH, P, D>(
+        &mut self,
+        _channel: &mut CH,
+        pin: &mut P,
+        delay: &mut D,
+    ) -> Result<i16, Error<E>>
+    where
+        CH: adc::Channel<
+            Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, alert::ConversionReady>,
+            ID = ChannelSelection,
+        >,
+        P: Wait,
+        D: DelayNs,
+    {
+        // The pin stays deasserted while idle, so only wait for a previous
+        // conversion if one was started.
+        if self.a_conversion_was_started {
+            self.wait_for_ready_pin(pin, delay).await?;
+        }
+        let (config, fsr) = self.channel_config(CH::channel());
+        self.start_measurement(config, fsr).await?;
+        self.wait_for_ready_pin(pin, delay).await?;
+        self.read_conversion().await
+    }
+
+    async fn wait_for_ready_pin<P: Wait, D: DelayNs>(
+        &mut self,
+        pin: &mut P,
+        delay: &mut D,
+    ) -> Result<(), Error<E>> {
+        let conversion_time_us = self.config.conversion_time_us::<CONV>();
+        let polarity = self.config.comparator_polarity();
+        let ready = {
+            let mut ready = pin!(async {
+                match polarity {
+                    ComparatorPolarity::ActiveLow => pin.wait_for_low().await,
+                    ComparatorPolarity::ActiveHigh => pin.wait_for_high().await,
+                }
+            });
+            let mut timeout = pin!(delay.delay_us(conversion_time_us));
+            poll_fn(|cx| match ready.as_mut().poll(cx) {
+                Poll::Ready(result) => Poll::Ready(Some(result)),
+                Poll::Pending => timeout.as_mut().poll(cx).map(|()| None),
+            })
+            .await
+        };
+        match ready {
+            Some(result) => result.map_err(|_| Error::Pin),
+            // The edge was missed or the pin is not connected.
+            None => self.poll_with_delay(delay).await,
+        }
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::Continuous, ALERT>
+where
+    I2C: I2c<Error = E>,
+    CONV: conversion::ConvertMeasurement,
+{
+    /// Change operating mode to OneShot
+    pub async fn into_one_shot(
+        mut self,
+    ) -> Result<
+        Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>,
+        ModeChangeError<E, Self>,
+    > {
+        if let Err(Error::I2C(e, _)) = self.set_operating_mode(OperatingMode::OneShot).await {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        Ok(self.into_mode(false))
+    }
+
+    /// Read the most recent measurement
+    pub async fn read(&mut self) -> Result<i16, Error<E>> {
+        let value = self.read_unchecked().await?;
+        self.checked_measurement(value)
+    }
+
+    /// Read the most recent measurement flagged with whether it was clipped
+    pub async fn read_measurement(&mut self) -> Result<Measurement, Error<E>> {
+        let value = self.read().await?;
+        Ok(Self::flagged_measurement(value))
+    }
+
+    /// Read the most recent measurement regardless of whether clipped
+    /// measurements are returned as errors.
+    async fn read_unchecked(&mut self) -> Result<i16, Error<E>> {
+        let value = self.iface.read_register(Register::CONVERSION).await?;
+        Ok(CONV::convert_measurement(value))
+    }
+
+    /// Read the most recent measurement in volts
+    pub async fn read_voltage(&mut self) -> Result<f32, Error<E>>
+    where
+        CONV: ic::Resolution,
+    {
+        let value = self.read().await?;
+        Ok(conversion::convert_to_volts::<CONV>(value, self.fsr))
+    }
+
+    /// Read the most recent measurement in millivolts, rounded to the
+    /// nearest millivolt.
+    pub async fn read_millivolts(&mut self) -> Result<i32, Error<E>>
+    where
+        CONV: ic::Resolution,
+    {
+        let value = self.read().await?;
+        Ok(conversion::convert_to_millivolts::<CONV>(value, self.fsr))
+    }
+
+    /// Select the channel for measurements.
+    ///
+    /// Note that when changing the channel in continuous conversion mode, the
+    /// ongoing conversion will be completed.
+    /// The following conversions will use the new channel configuration.
+    pub async fn select_channel<CH>(&mut self, _channel: &mut CH) -> Result<(), Error<E>>
+    where
+        CH: adc::Channel<
+            Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::OneShot, ALERT>,
+            ID = ChannelSelection,
+        >,
+    {
+        let (config, fsr) = self.channel_config(CH::channel());
+        self.write_config(config).await?;
+        self.fsr = fsr;
+        Ok(())
+    }
+}
+
+impl<I2C, IC, CONV, ALERT, E> Ads1x1x<AsyncI2cInterface<I2C>, IC, CONV, mode::Continuous, ALERT>
+where
+    I2C: I2c<Error = E>,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Read the most recent measurement if it was not returned by this
+    /// method before.
+    ///
+    /// Returns `None` until a fresh measurement is available.
+    ///
+    /// See [`read_new()`](struct.Ads1x1x.html#method.read_new).
+    pub async fn read_new(&mut self, now_us: u32) -> Result<Option<i16>, Error<E>> {
+        if self.fresh_sample_age_us(now_us).is_none() {
+            return Ok(None);
+        }
+        let value = self.read_unchecked().await?;
+        self.mark_sample_returned(now_us);
+        self.checked_measurement(value).map(Some)
+    }
+
+    /// Read a fresh measurement into the stream if there is one.
+    ///
+    /// Returns whether a measurement was read.
+    ///
+    /// See [`stream_poll()`](struct.Ads1x1x.html#method.stream_poll).
+    pub async fn stream_poll<S: SampleSink>(
+        &mut self,
+        stream: &mut Stream<S>,
+        now_us: u32,
+    ) -> Result<bool, Error<E>> {
+        let age_us = match self.fresh_sample_age_us(now_us) {
+            Some(age_us) => age_us,
+            None => return Ok(false),
+        };
+        let value = self.read_unchecked().await?;
+        stream.add_overruns(age_us / self.config.conversion_period_us::<CONV>());
+        self.mark_sample_returned(now_us);
+        let value = self.checked_measurement(value)?;
+        stream.push(Sample {
+            value,
+            timestamp_us: Some(now_us),
+        });
+        Ok(true)
+    }
+
+    /// Measure the next channel of a scan and return it together with the
+    /// measurement.
+    ///
+    /// See [`read_scan()`](struct.Ads1x1x.html#method.read_scan).
+    pub async fn read_scan<D, const N: usize>(
+        &mut self,
+        scan: &mut ChannelScan<N>,
+        delay: &mut D,
+    ) -> Result<(ChannelSelection, i16), Error<E>>
+    where
+        D: DelayNs,
+    {
+        let channel = scan.next_channel().ok_or(Error::InvalidInputData)?;
+        let (config, fsr) = self.channel_config(channel);
+        let mut wait_us = config.conversion_time_us::<CONV>();
+        if config != self.config {
+            wait_us += self.config.conversion_time_us::<CONV>();
+            self.write_config(config).await?;
+            self.fsr = fsr;
+        }
+        delay.delay_us(wait_us).await;
+        let value = self.read_unchecked().await?;
+        scan.advance();
+        Ok((channel, self.checked_measurement(value)?))
+    }
+}
@@ -0,0 +1,115 @@
+//! Auto-ranging measurements
+
+use crate::{
+    conversion, ic, interface, mode, Ads1x1x, AutoRange, AutoRangeMeasurement, ChannelSelection,
+    Error, SequenceStep,
+};
+use embedded_hal::adc;
+
+/// Direction in which the full-scale range was changed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RangeStep {
+    Wider,
+    Narrower,
+}
+
+impl AutoRange {
+    /// Range to continue with after the given measurement, `None` if the
+    /// measurement is final.
+    ///
+    /// Saturated measurements always widen the range while no narrower
+    /// range is tried after widening it, so that an input at the boundary
+    /// between two ranges does not cause oscillation.
+    pub(crate) fn next_step<CONV: conversion::ConvertMeasurement + ic::Resolution>(
+        &self,
+        value: i16,
+        previous: Option<RangeStep>,
+    ) -> Option<RangeStep> {
+        let used = (f32::from(value) / CONV::FULL_SCALE_CODE as f32).abs();
+        if CONV::clipping(value).is_some() {
+            if self.full_scale_range.wider().is_some() {
+                return Some(RangeStep::Wider);
+            }
+        } else if used < self.step_down_fraction
+            && previous != Some(RangeStep::Wider)
+            && self.full_scale_range.narrower().is_some()
+        {
+            return Some(RangeStep::Narrower);
+        }
+        None
+    }
+
+    /// Change the range in the given direction.
+    pub(crate) fn take_step(&mut self, step: RangeStep) {
+        let range = match step {
+            RangeStep::Wider => self.full_scale_range.wider(),
+            RangeStep::Narrower => self.full_scale_range.narrower(),
+        };
+        if let Some(range) = range {
+            self.full_scale_range = range;
+        }
+    }
+
+    /// Measurement result with the current range.
+    pub(crate) fn measurement<CONV: ic::Resolution>(&self, value: i16) -> AutoRangeMeasurement {
+        AutoRangeMeasurement {
+            raw: value,
+            volts: conversion::convert_to_volts::<CONV>(value, self.full_scale_range),
+            full_scale_range: self.full_scale_range,
+        }
+    }
+}
+
+impl<DI, IC, CONV, ALERT, E> Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>
+where
+    DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
+    IC: ic::Tier2Features + ic::Features,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Make a measurement on the specified channel choosing the full-scale
+    /// range automatically.
+    ///
+    /// The measurement starts with the full-scale range of `auto_range`.
+    /// If it saturates at the limits of the output codes, it is repeated
+    /// with the next wider range. If it uses less than the configured
+    /// fraction of the range, it is repeated with the next narrower range.
+    /// Once the range was widened, it is not narrowed again within the same
+    /// call, which bounds the number of conversions. The final range is kept
+    /// in `auto_range` for the next call and remains selected in the device.
+    ///
+    /// Each conversion is awaited as in
+    /// [`read_blocking()`](struct.Ads1x1x.html#method.read_blocking), with
+    /// the range selected in the same configuration write which starts it.
+    pub fn read_auto_range<CH, D>(
+        &mut self,
+        _channel: &mut CH,
+        auto_range: &mut AutoRange,
+        delay: &mut D,
+    ) -> Result<AutoRangeMeasurement, Error<E>>
+    where
+        CH: adc::Channel<Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>, ID = ChannelSelection>,
+        D: interface::Delay,
+    {
+        if self.a_conversion_was_started {
+            self.wait_for_conversion(delay)?;
+        }
+        let mut previous = None;
+        loop {
+            let step =
+                SequenceStep::new(CH::channel()).with_full_scale_range(auto_range.full_scale_range);
+            self.start_sequence_step(&step)?;
+            self.wait_for_conversion(delay)?;
+            let value = self.read_sequence_step()?;
+            match auto_range.next_step::<CONV>(value, previous) {
+                Some(step) => {
+                    auto_range.take_step(step);
+                    previous = Some(step);
+                }
+                None => {
+                    let value = self.checked_measurement(value)?;
+                    return Ok(auto_range.measurement::<CONV>(value));
+                }
+            }
+        }
+    }
+}
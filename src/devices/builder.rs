@@ -0,0 +1,142 @@
+//! Configuration builder
+
+use crate::{
+    alert, ic, interface, Ads1x1x, ComparatorLatching, ComparatorMode, ComparatorPolarity,
+    ComparatorQueue, Config, ConfigBuilder, DataRate12Bit, DataRate16Bit, Error, FullScaleRange,
+    Register,
+};
+use core::marker::PhantomData;
+
+impl<DI, IC, CONV, MODE, ALERT, E> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    DI: interface::WriteData<Error = E>,
+{
+    /// Change several settings with a single write to the configuration register.
+    ///
+    /// The closure receives a [`ConfigBuilder`](struct.ConfigBuilder.html)
+    /// starting from the current configuration and returns it with the
+    /// desired changes. This avoids the intermediate states which result
+    /// from calling the individual setters one after another.
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "eh02")]
+    /// # fn main() {
+    /// use ads1x1x::{
+    ///     Ads1x1x, ComparatorLatching, ComparatorMode, DataRate16Bit, FullScaleRange, SlaveAddr,
+    /// };
+    /// use linux_embedded_hal::I2cdev;
+    ///
+    /// let dev = I2cdev::new("/dev/i2c-1").unwrap();
+    /// let mut adc = Ads1x1x::new_ads1115(dev, SlaveAddr::default());
+    /// adc.configure(|c| {
+    ///     c.data_rate(DataRate16Bit::Sps860)
+    ///         .full_scale_range(FullScaleRange::Within4_096V)
+    ///         .comparator_mode(ComparatorMode::Window)
+    ///         .comparator_latching(ComparatorLatching::Latching)
+    /// })
+    /// .unwrap();
+    /// # }
+    /// # #[cfg(not(feature = "eh02"))]
+    /// # fn main() {}
+    /// ```
+    pub fn configure<F>(&mut self, f: F) -> Result<(), Error<E>>
+    where
+        IC: ic::Features,
+        F: FnOnce(ConfigBuilder<IC, CONV, ALERT>) -> ConfigBuilder<IC, CONV, ALERT>,
+    {
+        let config = self.config_builder(f);
+        self.iface.write_register(Register::CONFIG, config.bits)?;
+        // The PGA bits have no effect in the ADS1x13.
+        if IC::TIER2 {
+            self.fsr = config.full_scale_range();
+        }
+        self.config = config;
+        Ok(())
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT> {
+    /// Apply the changes of the closure to the cached configuration.
+    pub(super) fn config_builder<F>(&self, f: F) -> Config
+    where
+        F: FnOnce(ConfigBuilder<IC, CONV, ALERT>) -> ConfigBuilder<IC, CONV, ALERT>,
+    {
+        let builder = ConfigBuilder {
+            config: self.config.clone(),
+            _ic: PhantomData,
+            _conv: PhantomData,
+            _alert: PhantomData,
+        };
+        f(builder).config
+    }
+}
+
+impl<IC, ALERT> ConfigBuilder<IC, ic::Resolution12Bit, ALERT> {
+    /// Set data rate
+    pub fn data_rate(self, rate: DataRate12Bit) -> Self {
+        ConfigBuilder {
+            config: self.config.with_data_rate_12bit(rate),
+            ..self
+        }
+    }
+}
+
+impl<IC, ALERT> ConfigBuilder<IC, ic::Resolution16Bit, ALERT> {
+    /// Set data rate
+    pub fn data_rate(self, rate: DataRate16Bit) -> Self {
+        ConfigBuilder {
+            config: self.config.with_data_rate_16bit(rate),
+            ..self
+        }
+    }
+}
+
+impl<IC, CONV, ALERT> ConfigBuilder<IC, CONV, ALERT>
+where
+    IC: ic::Tier2Features,
+{
+    /// Set the input voltage measurable range
+    pub fn full_scale_range(self, range: FullScaleRange) -> Self {
+        ConfigBuilder {
+            config: self.config.with_full_scale_range(range),
+            ..self
+        }
+    }
+
+    /// Set comparator mode
+    pub fn comparator_mode(self, mode: ComparatorMode) -> Self {
+        ConfigBuilder {
+            config: self.config.with_comparator_mode(mode),
+            ..self
+        }
+    }
+
+    /// Set comparator polarity
+    pub fn comparator_polarity(self, polarity: ComparatorPolarity) -> Self {
+        ConfigBuilder {
+            config: self.config.with_comparator_polarity(polarity),
+            ..self
+        }
+    }
+
+    /// Set comparator latching
+    pub fn comparator_latching(self, latching: ComparatorLatching) -> Self {
+        ConfigBuilder {
+            config: self.config.with_comparator_latching(latching),
+            ..self
+        }
+    }
+}
+
+impl<IC, CONV> ConfigBuilder<IC, CONV, alert::Comparator>
+where
+    IC: ic::Tier2Features,
+{
+    /// Set the comparator alert queue
+    pub fn comparator_queue(self, queue: ComparatorQueue) -> Self {
+        ConfigBuilder {
+            config: self.config.with_comparator_queue(queue),
+            ..self
+        }
+    }
+}
@@ -1,17 +1,181 @@
 //! Common functions
 
-use crate::{devices::OperatingMode, interface, Ads1x1x, BitFlags, Config, Error, Register};
+use crate::{
+    alert, channels::ChannelSelection, conversion, ic, interface, mode, Ads1x1x, BitFlags, Config,
+    Configuration, Error, FullScaleRange, Measurement, ModeChangeError, OperatingMode, Register,
+};
+use core::marker::PhantomData;
 
-impl<DI, IC, CONV, MODE, E> Ads1x1x<DI, IC, CONV, MODE>
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT> {
+    /// Convert into a driver instance in a different operating mode.
+    ///
+    /// This does not communicate with the device.
+    pub(super) fn into_mode<NEWMODE>(
+        self,
+        a_conversion_was_started: bool,
+    ) -> Ads1x1x<DI, IC, CONV, NEWMODE, ALERT> {
+        Ads1x1x {
+            iface: self.iface,
+            config: self.config,
+            fsr: self.fsr,
+            a_conversion_was_started,
+            conversion_poll_limit: self.conversion_poll_limit,
+            conversion_polls: 0,
+            channel_profiles: self.channel_profiles,
+            fresh_sample: None,
+            out_of_range_error: self.out_of_range_error,
+            _conv: PhantomData,
+            _ic: PhantomData,
+            _mode: PhantomData,
+            _alert: PhantomData,
+        }
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT> {
+    /// Convert into a driver instance with a different ALERT/RDY pin role.
+    ///
+    /// This does not communicate with the device.
+    pub(super) fn into_alert<NEWALERT>(self) -> Ads1x1x<DI, IC, CONV, MODE, NEWALERT> {
+        Ads1x1x {
+            iface: self.iface,
+            config: self.config,
+            fsr: self.fsr,
+            a_conversion_was_started: self.a_conversion_was_started,
+            conversion_poll_limit: self.conversion_poll_limit,
+            conversion_polls: self.conversion_polls,
+            channel_profiles: self.channel_profiles,
+            fresh_sample: self.fresh_sample,
+            out_of_range_error: self.out_of_range_error,
+            _conv: PhantomData,
+            _ic: PhantomData,
+            _mode: PhantomData,
+            _alert: PhantomData,
+        }
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    CONV: ic::Resolution,
+{
+    /// Get the configuration currently cached in this driver.
+    ///
+    /// This does not communicate with the device. To read the configuration
+    /// of the device see [`read_configuration()`](struct.Ads1x1x.html#method.read_configuration).
+    pub fn configuration(&self) -> Configuration<CONV::DataRate> {
+        self.config.decode::<CONV>()
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT> {
+    /// Get the currently selected input channel.
+    ///
+    /// Devices without input multiplexer always measure
+    /// [`DifferentialA0A1`](enum.ChannelSelection.html#variant.DifferentialA0A1).
+    pub fn selected_channel(&self) -> ChannelSelection {
+        self.config.channel()
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT> {
+    /// Configuration selecting the channel with its profile applied and the
+    /// full-scale range it results in.
+    ///
+    /// See [`set_channel_profile()`](struct.Ads1x1x.html#method.set_channel_profile).
+    pub(crate) fn channel_config(&self, channel: ChannelSelection) -> (Config, FullScaleRange) {
+        let config = self.config.with_mux_bits(channel);
+        match self.channel_profiles[channel.index()] {
+            Some(profile) => (
+                config
+                    .with_full_scale_range(profile.full_scale_range)
+                    .with_data_rate_bits(profile.data_rate_bits),
+                profile.full_scale_range,
+            ),
+            None => (config, self.fsr),
+        }
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT> {
+    /// Set the maximum number of times the device is polled for the end of a
+    /// one-shot conversion before giving up with `Error::Timeout`.
+    ///
+    /// When the limit is reached, the cached configuration is written to the
+    /// device again, which starts a new conversion and restores the device
+    /// configuration in case the device was reset in the meantime.
+    ///
+    /// By default there is no limit for [`read()`][read_os] and
+    /// [`read_blocking()`](struct.Ads1x1x.html#method.read_blocking)
+    /// gives up after 16 polls.
+    /// The asynchronous `read()` gives up by default once polling has taken
+    /// longer than the conversion time of the configured data rate.
+    ///
+    /// This does not communicate with the device.
+    ///
+    /// [read_os]: struct.Ads1x1x.html#method.read
+    pub fn set_conversion_poll_limit(&mut self, limit: Option<u32>) {
+        self.conversion_poll_limit = limit;
+        self.conversion_polls = 0;
+    }
+
+    /// Get the maximum number of times the device is polled for the end of a
+    /// one-shot conversion.
+    ///
+    /// See [`set_conversion_poll_limit()`](struct.Ads1x1x.html#method.set_conversion_poll_limit).
+    pub fn conversion_poll_limit(&self) -> Option<u32> {
+        self.conversion_poll_limit
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT> {
+    /// Set whether measurements clipped at the limits of the full-scale
+    /// range are returned as `Error::OutOfRange` instead of as values.
+    ///
+    /// This applies to all reads in one-shot and continuous-conversion mode.
+    /// Disabled by default.
+    ///
+    /// This does not communicate with the device.
+    pub fn set_out_of_range_error(&mut self, enabled: bool) {
+        self.out_of_range_error = enabled;
+    }
+
+    /// Get whether clipped measurements are returned as `Error::OutOfRange`.
+    ///
+    /// See [`set_out_of_range_error()`](struct.Ads1x1x.html#method.set_out_of_range_error).
+    pub fn out_of_range_error(&self) -> bool {
+        self.out_of_range_error
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    CONV: conversion::ConvertMeasurement,
+{
+    /// Return the measurement or `Error::OutOfRange` if it is clipped and
+    /// this is enabled.
+    pub(crate) fn checked_measurement<E>(&self, value: i16) -> Result<i16, Error<E>> {
+        if self.out_of_range_error && CONV::clipping(value).is_some() {
+            Err(Error::OutOfRange)
+        } else {
+            Ok(value)
+        }
+    }
+
+    pub(crate) fn flagged_measurement(value: i16) -> Measurement {
+        Measurement {
+            raw: value,
+            clipping: CONV::clipping(value),
+        }
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT, E> Ads1x1x<DI, IC, CONV, MODE, ALERT>
 where
     DI: interface::WriteData<Error = E> + interface::ReadData<Error = E>,
 {
     pub(super) fn set_operating_mode(&mut self, mode: OperatingMode) -> Result<(), Error<E>> {
-        let config;
-        match mode {
-            OperatingMode::OneShot => config = self.config.with_high(BitFlags::OP_MODE),
-            OperatingMode::Continuous => config = self.config.with_low(BitFlags::OP_MODE),
-        }
+        let config = self.config.with_operating_mode(mode);
         self.iface.write_register(Register::CONFIG, config.bits)?;
         self.config = config;
         Ok(())
@@ -36,7 +200,126 @@ where
     /// example through an I2C general-call Reset command, which was not done
     /// through this driver to ensure that the configurations in the device
     /// and in the driver match.
+    /// See [`general_call_reset()`](struct.Ads1x1x.html#method.general_call_reset).
     pub fn reset_internal_driver_state(&mut self) {
         self.config = Config::default();
+        self.fsr = FullScaleRange::default();
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT, E> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    DI: interface::WriteData<Error = E>,
+{
+    /// Reset the device through an I2C general-call Reset command and reset
+    /// the internal state of this driver accordingly.
+    ///
+    /// *Note:* All devices on the bus which respond to general calls are
+    /// reset by this command, not only this device.
+    ///
+    /// After the reset the device is in its power-up state, which is the
+    /// one-shot operating mode with the comparator disabled.
+    pub fn general_call_reset(
+        mut self,
+    ) -> Result<Ads1x1x<DI, IC, CONV, mode::OneShot, alert::Disabled>, ModeChangeError<E, Self>>
+    {
+        if let Err(Error::I2C(e, _)) = self.iface.write_general_call_reset() {
+            return Err(ModeChangeError::I2C(e, self));
+        }
+        self.config = Config::default();
+        self.fsr = FullScaleRange::default();
+        Ok(self.into_mode(false).into_alert())
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT, E> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    DI: interface::WriteData<Error = E> + interface::ReadData<Error = E>,
+    CONV: ic::Resolution,
+{
+    /// Read the configuration register of the device and decode it.
+    ///
+    /// This does not alter the configuration cached in this driver.
+    /// See [`sync_from_device()`](struct.Ads1x1x.html#method.sync_from_device).
+    pub fn read_configuration(&mut self) -> Result<Configuration<CONV::DataRate>, Error<E>> {
+        let config = Config {
+            bits: self.iface.read_register(Register::CONFIG)?,
+        };
+        Ok(config.decode::<CONV>())
+    }
+
+    /// Read the configuration register of the device and overwrite the
+    /// configuration cached in this driver with it.
+    ///
+    /// This allows attaching to a device which was already configured, for
+    /// example before a reset of the microcontroller.
+    /// The decoded device configuration is returned.
+    ///
+    /// *Note:* The operating mode is encoded in the type of this driver and
+    /// is therefore not taken over. If the returned operating mode does not
+    /// match the one of this driver, change it with
+    /// [`into_continuous()`](struct.Ads1x1x.html#method.into_continuous) or
+    /// [`into_one_shot()`](struct.Ads1x1x.html#method.into_one_shot).
+    ///
+    /// The ALERT/RDY pin role is encoded in the type of this driver as well.
+    /// If the comparator of the device is enabled while this driver has it
+    /// disabled or vice versa, `Error::ComparatorMismatch` is returned and
+    /// the cached configuration is left unchanged.
+    /// The full-scale range stays fixed at ±2.048 V in the ADS1x13.
+    pub fn sync_from_device(&mut self) -> Result<Configuration<CONV::DataRate>, Error<E>>
+    where
+        IC: ic::Features,
+        ALERT: alert::Role,
+    {
+        let bits = self.iface.read_register(Register::CONFIG)?;
+        let device_config = Config { bits };
+        self.take_over_config(&device_config)?;
+        Ok(device_config.decode::<CONV>())
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    IC: ic::Features,
+    ALERT: alert::Role,
+{
+    /// Take over the configuration read from the device.
+    ///
+    /// See [`sync_from_device()`](struct.Ads1x1x.html#method.sync_from_device).
+    pub(crate) fn take_over_config<E>(&mut self, device_config: &Config) -> Result<(), Error<E>> {
+        if IC::TIER2 && device_config.comparator_queue().is_some() != ALERT::COMPARATOR_ENABLED {
+            return Err(Error::ComparatorMismatch);
+        }
+        self.config = self.config.synced_with(device_config);
+        if IC::TIER2 {
+            self.fsr = device_config.full_scale_range();
+        }
+        Ok(())
+    }
+}
+
+impl Config {
+    /// Take over the device configuration keeping the operating mode and
+    /// the OS bit of this configuration.
+    pub(super) fn synced_with(&self, device_config: &Config) -> Self {
+        let kept = BitFlags::OS | BitFlags::OP_MODE;
+        Config {
+            bits: (device_config.bits & !kept) | (self.bits & kept),
+        }
+    }
+
+    pub(super) fn with_operating_mode(&self, mode: OperatingMode) -> Self {
+        match mode {
+            OperatingMode::OneShot => self.with_high(BitFlags::OP_MODE),
+            OperatingMode::Continuous => self.with_low(BitFlags::OP_MODE),
+        }
+    }
+
+    pub(crate) fn operating_mode(&self) -> OperatingMode {
+        if self.is_high(BitFlags::OP_MODE) {
+            OperatingMode::OneShot
+        } else {
+            OperatingMode::Continuous
+        }
     }
 }
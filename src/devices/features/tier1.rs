@@ -1,41 +1,89 @@
 //! Common functions
 
 use crate::{
-    ic, interface, Ads1x1x, BitFlags as BF, DataRate12Bit, DataRate16Bit, Error, Register,
+    ic, interface, Ads1x1x, BitFlags as BF, Config, DataRate12Bit, DataRate16Bit, Error, Register,
 };
 
-impl<DI, IC, MODE, E> Ads1x1x<DI, IC, ic::Resolution12Bit, MODE>
+impl<DI, IC, MODE, ALERT, E> Ads1x1x<DI, IC, ic::Resolution12Bit, MODE, ALERT>
 where
     DI: interface::WriteData<Error = E>,
 {
     /// Set data rate
     pub fn set_data_rate(&mut self, rate: DataRate12Bit) -> Result<(), Error<E>> {
-        use crate::DataRate12Bit as DR;
-        let cfg = self.config.clone();
-        let config = match rate {
-            DR::Sps128 => cfg.with_low(BF::DR2).with_low(BF::DR1).with_low(BF::DR0),
-            DR::Sps250 => cfg.with_low(BF::DR2).with_low(BF::DR1).with_high(BF::DR0),
-            DR::Sps490 => cfg.with_low(BF::DR2).with_high(BF::DR1).with_low(BF::DR0),
-            DR::Sps920 => cfg.with_low(BF::DR2).with_high(BF::DR1).with_high(BF::DR0),
-            DR::Sps1600 => cfg.with_high(BF::DR2).with_low(BF::DR1).with_low(BF::DR0),
-            DR::Sps2400 => cfg.with_high(BF::DR2).with_low(BF::DR1).with_high(BF::DR0),
-            DR::Sps3300 => cfg.with_high(BF::DR2).with_high(BF::DR1).with_low(BF::DR0),
-        };
+        let config = self.config.with_data_rate_12bit(rate);
         self.iface.write_register(Register::CONFIG, config.bits)?;
         self.config = config;
         Ok(())
     }
 }
 
-impl<DI, IC, MODE, E> Ads1x1x<DI, IC, ic::Resolution16Bit, MODE>
+impl<DI, IC, MODE, ALERT, E> Ads1x1x<DI, IC, ic::Resolution16Bit, MODE, ALERT>
 where
     DI: interface::WriteData<Error = E>,
 {
     /// Set data rate
     pub fn set_data_rate(&mut self, rate: DataRate16Bit) -> Result<(), Error<E>> {
+        let config = self.config.with_data_rate_16bit(rate);
+        self.iface.write_register(Register::CONFIG, config.bits)?;
+        self.config = config;
+        Ok(())
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    CONV: ic::Resolution,
+{
+    /// Get the currently configured data rate
+    pub fn data_rate(&self) -> CONV::DataRate {
+        CONV::data_rate(self.config.data_rate_bits())
+    }
+}
+
+impl Config {
+    pub(crate) fn data_rate_bits(&self) -> u8 {
+        ((self.bits & (BF::DR2 | BF::DR1 | BF::DR0)) >> 5) as u8
+    }
+
+    pub(crate) fn with_data_rate_bits(&self, dr_bits: u8) -> Self {
+        let mask = BF::DR2 | BF::DR1 | BF::DR0;
+        Config {
+            bits: (self.bits & !mask) | ((u16::from(dr_bits) << 5) & mask),
+        }
+    }
+
+    /// Maximum duration of a conversion with the configured data rate in microseconds.
+    pub(crate) fn conversion_time_us<CONV: ic::Resolution>(&self) -> u32 {
+        let sps = u32::from(CONV::SAMPLE_RATES[usize::from(self.data_rate_bits())]);
+        // The internal oscillator may run up to 10% slower than nominal.
+        1_100_000 / sps + 1
+    }
+
+    /// Nominal time between conversions in continuous-conversion mode with
+    /// the configured data rate in microseconds.
+    pub(crate) fn conversion_period_us<CONV: ic::Resolution>(&self) -> u32 {
+        let sps = u32::from(CONV::SAMPLE_RATES[usize::from(self.data_rate_bits())]);
+        1_000_000 / sps
+    }
+
+    pub(crate) fn with_data_rate_12bit(&self, rate: DataRate12Bit) -> Self {
+        use crate::DataRate12Bit as DR;
+        let cfg = self.clone();
+        match rate {
+            DR::Sps128 => cfg.with_low(BF::DR2).with_low(BF::DR1).with_low(BF::DR0),
+            DR::Sps250 => cfg.with_low(BF::DR2).with_low(BF::DR1).with_high(BF::DR0),
+            DR::Sps490 => cfg.with_low(BF::DR2).with_high(BF::DR1).with_low(BF::DR0),
+            DR::Sps920 => cfg.with_low(BF::DR2).with_high(BF::DR1).with_high(BF::DR0),
+            DR::Sps1600 => cfg.with_high(BF::DR2).with_low(BF::DR1).with_low(BF::DR0),
+            DR::Sps2400 => cfg.with_high(BF::DR2).with_low(BF::DR1).with_high(BF::DR0),
+            DR::Sps3300 => cfg.with_high(BF::DR2).with_high(BF::DR1).with_low(BF::DR0),
+        }
+    }
+
+    pub(crate) fn with_data_rate_16bit(&self, rate: DataRate16Bit) -> Self {
         use crate::DataRate16Bit as DR;
-        let cfg = self.config.clone();
-        let config = match rate {
+        let cfg = self.clone();
+        match rate {
             DR::Sps8 => cfg.with_low(BF::DR2).with_low(BF::DR1).with_low(BF::DR0),
             DR::Sps16 => cfg.with_low(BF::DR2).with_low(BF::DR1).with_high(BF::DR0),
             DR::Sps32 => cfg.with_low(BF::DR2).with_high(BF::DR1).with_low(BF::DR0),
@@ -44,9 +92,6 @@ where
             DR::Sps250 => cfg.with_high(BF::DR2).with_low(BF::DR1).with_high(BF::DR0),
             DR::Sps475 => cfg.with_high(BF::DR2).with_high(BF::DR1).with_low(BF::DR0),
             DR::Sps860 => cfg.with_high(BF::DR2).with_high(BF::DR1).with_high(BF::DR0),
-        };
-        self.iface.write_register(Register::CONFIG, config.bits)?;
-        self.config = config;
-        Ok(())
+        }
     }
 }
@@ -3,50 +3,127 @@
 //! These are the features included only in ADS1x14, ADS1x15
 
 use crate::{
-    conversion, ic, interface, Ads1x1x, BitFlags as BF, ComparatorLatching, ComparatorMode,
-    ComparatorPolarity, ComparatorQueue, Error, FullScaleRange, Register,
+    alert, conversion, ic, interface, types::StoredChannelProfile, Ads1x1x, BitFlags as BF,
+    ChannelProfile, ChannelSelection, ComparatorLatching, ComparatorMode, ComparatorPolarity,
+    ComparatorQueue, Config, Error, FullScaleRange, ModeChangeError, Register, SlaveAddr,
 };
+use core::iter;
 
-impl<DI, IC, CONV, MODE, E> Ads1x1x<DI, IC, CONV, MODE>
+impl<DI, IC, CONV, MODE, ALERT, E> Ads1x1x<DI, IC, CONV, MODE, ALERT>
 where
     DI: interface::WriteData<Error = E>,
     IC: ic::Tier2Features,
-    CONV: conversion::ConvertThreshold<E>,
 {
     /// Set the input voltage measurable range
     ///
     /// This configures the programmable gain amplifier and determines the measurable input voltage range.
     pub fn set_full_scale_range(&mut self, range: FullScaleRange) -> Result<(), Error<E>> {
-        use crate::FullScaleRange as FSR;
-        let cfg = self.config.clone();
-        let config = match range {
-            FSR::Within6_144V => cfg.with_low(BF::PGA2).with_low(BF::PGA1).with_low(BF::PGA0),
-            FSR::Within4_096V => cfg
-                .with_low(BF::PGA2)
-                .with_low(BF::PGA1)
-                .with_high(BF::PGA0),
-            FSR::Within2_048V => cfg
-                .with_low(BF::PGA2)
-                .with_high(BF::PGA1)
-                .with_low(BF::PGA0),
-            FSR::Within1_024V => cfg
-                .with_low(BF::PGA2)
-                .with_high(BF::PGA1)
-                .with_high(BF::PGA0),
-            FSR::Within0_512V => cfg
-                .with_high(BF::PGA2)
-                .with_low(BF::PGA1)
-                .with_low(BF::PGA0),
-            FSR::Within0_256V => cfg
-                .with_high(BF::PGA2)
-                .with_low(BF::PGA1)
-                .with_high(BF::PGA0),
-        };
+        let config = self.config.with_full_scale_range(range);
+        self.iface.write_register(Register::CONFIG, config.bits)?;
+        self.config = config;
+        self.fsr = range;
+        Ok(())
+    }
+
+    /// Set comparator mode
+    pub fn set_comparator_mode(&mut self, mode: ComparatorMode) -> Result<(), Error<E>> {
+        let config = self.config.with_comparator_mode(mode);
+        self.iface.write_register(Register::CONFIG, config.bits)?;
+        self.config = config;
+        Ok(())
+    }
+
+    /// Set comparator polarity
+    ///
+    /// This also determines the polarity of the ALERT/RDY pin when used as
+    /// conversion-ready pin.
+    pub fn set_comparator_polarity(
+        &mut self,
+        polarity: ComparatorPolarity,
+    ) -> Result<(), Error<E>> {
+        let config = self.config.with_comparator_polarity(polarity);
         self.iface.write_register(Register::CONFIG, config.bits)?;
         self.config = config;
         Ok(())
     }
 
+    /// Set comparator latching
+    pub fn set_comparator_latching(
+        &mut self,
+        latching: ComparatorLatching,
+    ) -> Result<(), Error<E>> {
+        let config = self.config.with_comparator_latching(latching);
+        self.iface.write_register(Register::CONFIG, config.bits)?;
+        self.config = config;
+        Ok(())
+    }
+
+    /// Write the configuration and change the role of the ALERT/RDY pin.
+    fn change_alert_role<NEWALERT>(
+        mut self,
+        config: Config,
+        ready_thresholds: bool,
+    ) -> Result<Ads1x1x<DI, IC, CONV, MODE, NEWALERT>, ModeChangeError<E, Self>> {
+        for (register, value) in Self::alert_role_writes(&config, ready_thresholds) {
+            if let Err(Error::I2C(e, _)) = self.iface.write_register(register, value) {
+                return Err(ModeChangeError::I2C(e, self));
+            }
+        }
+        self.config = config;
+        Ok(self.into_alert())
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    IC: ic::Tier2Features,
+{
+    /// Register writes changing the role of the ALERT/RDY pin with the
+    /// configuration.
+    ///
+    /// If requested, the thresholds are set to the values required for the
+    /// conversion-ready function first.
+    pub(crate) fn alert_role_writes(
+        config: &Config,
+        ready_thresholds: bool,
+    ) -> impl Iterator<Item = (u8, u16)> {
+        let thresholds: &[(u8, u16)] = if ready_thresholds {
+            &[(Register::HIGH_TH, 0x8000), (Register::LOW_TH, 0)]
+        } else {
+            &[]
+        };
+        thresholds
+            .iter()
+            .copied()
+            .chain(iter::once((Register::CONFIG, config.bits)))
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    IC: ic::Tier2Features,
+    CONV: ic::Resolution,
+{
+    /// Convert comparator lower and upper thresholds in volts into raw values.
+    ///
+    /// See [`set_threshold_window_voltage()`](struct.Ads1x1x.html#method.set_threshold_window_voltage).
+    pub(crate) fn threshold_window<E>(&self, low: f32, high: f32) -> Result<(i16, i16), Error<E>> {
+        let low = conversion::convert_from_volts::<CONV, E>(low, self.fsr)?;
+        let high = conversion::convert_from_volts::<CONV, E>(high, self.fsr)?;
+        if low > high {
+            return Err(Error::InvalidInputData);
+        }
+        Ok((low, high))
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT, E> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    DI: interface::WriteData<Error = E>,
+    IC: ic::Tier2Features,
+    CONV: conversion::ConvertThreshold<E> + ic::Resolution,
+    ALERT: alert::ConfigurableThresholds,
+{
     /// Set raw comparator lower threshold
     ///
     /// The input value must be within `[2047..-2048]` for 12-bit devices (`ADS101x`)
@@ -69,91 +146,365 @@ where
         self.iface.write_register(Register::HIGH_TH, register_value)
     }
 
-    /// Set comparator mode
-    pub fn set_comparator_mode(&mut self, mode: ComparatorMode) -> Result<(), Error<E>> {
-        let config = match mode {
-            ComparatorMode::Traditional => self.config.with_low(BF::COMP_MODE),
-            ComparatorMode::Window => self.config.with_high(BF::COMP_MODE),
-        };
-        self.iface.write_register(Register::CONFIG, config.bits)?;
-        self.config = config;
-        Ok(())
+    /// Set comparator lower threshold in volts
+    ///
+    /// The voltage is converted into the corresponding raw value using the
+    /// configured full-scale range and rounded to the nearest value.
+    /// Returns `Error::InvalidInputData` if the voltage is outside of the
+    /// full-scale range.
+    ///
+    /// Note that the thresholds are not adjusted when the full-scale range
+    /// is changed afterwards.
+    pub fn set_low_threshold_voltage(&mut self, volts: f32) -> Result<(), Error<E>> {
+        let value = conversion::convert_from_volts::<CONV, E>(volts, self.fsr)?;
+        self.set_low_threshold_raw(value)
     }
 
-    /// Set comparator polarity
-    pub fn set_comparator_polarity(
-        &mut self,
-        polarity: ComparatorPolarity,
-    ) -> Result<(), Error<E>> {
-        let config = match polarity {
-            ComparatorPolarity::ActiveLow => self.config.with_low(BF::COMP_POL),
-            ComparatorPolarity::ActiveHigh => self.config.with_high(BF::COMP_POL),
-        };
-        self.iface.write_register(Register::CONFIG, config.bits)?;
-        self.config = config;
-        Ok(())
+    /// Set comparator upper threshold in volts
+    ///
+    /// See [`set_low_threshold_voltage()`](struct.Ads1x1x.html#method.set_low_threshold_voltage).
+    pub fn set_high_threshold_voltage(&mut self, volts: f32) -> Result<(), Error<E>> {
+        let value = conversion::convert_from_volts::<CONV, E>(volts, self.fsr)?;
+        self.set_high_threshold_raw(value)
     }
 
-    /// Set comparator latching
-    pub fn set_comparator_latching(
-        &mut self,
-        latching: ComparatorLatching,
-    ) -> Result<(), Error<E>> {
-        let config = match latching {
-            ComparatorLatching::Nonlatching => self.config.with_low(BF::COMP_LAT),
-            ComparatorLatching::Latching => self.config.with_high(BF::COMP_LAT),
-        };
-        self.iface.write_register(Register::CONFIG, config.bits)?;
-        self.config = config;
-        Ok(())
+    /// Set comparator lower and upper thresholds in volts
+    ///
+    /// Both voltages are validated before any of them is written.
+    /// Returns `Error::InvalidInputData` if any of them is outside of the
+    /// full-scale range or if the lower threshold is greater than the upper
+    /// threshold.
+    /// See [`set_low_threshold_voltage()`](struct.Ads1x1x.html#method.set_low_threshold_voltage).
+    pub fn set_threshold_window_voltage(&mut self, low: f32, high: f32) -> Result<(), Error<E>> {
+        let (low, high) = self.threshold_window(low, high)?;
+        self.set_low_threshold_raw(low)?;
+        self.set_high_threshold_raw(high)
+    }
+}
+
+impl<DI, IC, CONV, MODE, E> Ads1x1x<DI, IC, CONV, MODE, alert::Disabled>
+where
+    DI: interface::WriteData<Error = E>,
+    IC: ic::Tier2Features,
+{
+    /// Activate the comparator with the given alert queue
+    ///
+    /// The ALERT/RDY pin is then driven by the comparator.
+    /// Set the thresholds before activating the comparator to avoid
+    /// spurious alerts.
+    pub fn enable_comparator(
+        self,
+        queue: ComparatorQueue,
+    ) -> Result<Ads1x1x<DI, IC, CONV, MODE, alert::Comparator>, ModeChangeError<E, Self>> {
+        let config = self.config.with_comparator_queue(queue);
+        self.change_alert_role(config, false)
     }
 
-    /// Activate comparator and set the alert queue
+    /// Use the ALERT/RDY pin as conversion-ready pin.
     ///
-    /// The comparator can be disabled with [`disable_comparator()`](struct.Ads1x1x.html#method.disable_comparator)
+    /// This the ALERT/RDY pin outputs the OS bit when in OneShot mode, and
+    /// provides a continuous-conversion ready pulse when in
+    /// continuous-conversion mode.
+    ///
+    /// The thresholds are overwritten with the values required for this
+    /// and the comparator queue is set to one conversion, as the pin is
+    /// only enabled while the comparator is active.
+    pub fn use_alert_rdy_pin_as_ready(
+        self,
+    ) -> Result<Ads1x1x<DI, IC, CONV, MODE, alert::ConversionReady>, ModeChangeError<E, Self>> {
+        let config = self.config.with_comparator_queue(ComparatorQueue::One);
+        self.change_alert_role(config, true)
+    }
+}
+
+impl<DI, IC, CONV, MODE, E> Ads1x1x<DI, IC, CONV, MODE, alert::Comparator>
+where
+    DI: interface::WriteData<Error = E>,
+    IC: ic::Tier2Features,
+{
+    /// Set the comparator alert queue
     pub fn set_comparator_queue(&mut self, queue: ComparatorQueue) -> Result<(), Error<E>> {
-        let config = match queue {
-            ComparatorQueue::One => self.config.with_low(BF::COMP_QUE1).with_low(BF::COMP_QUE0),
-            ComparatorQueue::Two => self.config.with_low(BF::COMP_QUE1).with_high(BF::COMP_QUE0),
-            ComparatorQueue::Four => self.config.with_high(BF::COMP_QUE1).with_low(BF::COMP_QUE0),
-        };
+        let config = self.config.with_comparator_queue(queue);
         self.iface.write_register(Register::CONFIG, config.bits)?;
         self.config = config;
         Ok(())
     }
 
+    /// Use the ALERT/RDY pin as conversion-ready pin.
+    ///
+    /// The thresholds are overwritten with the values required for this.
+    /// The comparator queue is kept.
+    /// See [`use_alert_rdy_pin_as_ready()`](struct.Ads1x1x.html#method.use_alert_rdy_pin_as_ready).
+    pub fn use_alert_rdy_pin_as_ready(
+        self,
+    ) -> Result<Ads1x1x<DI, IC, CONV, MODE, alert::ConversionReady>, ModeChangeError<E, Self>> {
+        let config = self.config.clone();
+        self.change_alert_role(config, true)
+    }
+
     /// Disable comparator (default)
     ///
     /// This will set the ALERT/RDY pin to high-impedance.
-    /// The comparator can be enabled by setting the comparator queue.
-    /// See [`set_comparator_queue()`](struct.Ads1x1x.html#method.set_comparator_queue)
-    pub fn disable_comparator(&mut self) -> Result<(), Error<E>> {
-        let config = self
-            .config
-            .with_high(BF::COMP_QUE1)
-            .with_high(BF::COMP_QUE0);
-        self.iface.write_register(Register::CONFIG, config.bits)?;
-        self.config = config;
-        Ok(())
+    pub fn disable_comparator(
+        self,
+    ) -> Result<Ads1x1x<DI, IC, CONV, MODE, alert::Disabled>, ModeChangeError<E, Self>> {
+        let config = self.config.with_comparator_disabled();
+        self.change_alert_role(config, false)
     }
+}
 
-    /// Use the ALERT/RDY pin as conversion-ready pin.
+impl<DI, IC, CONV, MODE, E> Ads1x1x<DI, IC, CONV, MODE, alert::Comparator>
+where
+    DI: interface::ReadAlertResponse<Error = E>,
+    IC: ic::Tier2Features,
+{
+    /// Send an SMBus alert response and return the address of the device
+    /// which asserted the ALERT/RDY line.
     ///
-    /// This the ALERT/RDY pin outputs the OS bit when in OneShot mode, and
-    /// provides a continuous-conversion ready pulse when in
-    /// continuous-conversion mode.
+    /// This clears the ALERT/RDY pin of the responding device if the
+    /// comparator is latching. If several devices share the ALERT/RDY line,
+    /// the device with the lowest address asserting it responds, which is
+    /// not necessarily this device.
+    /// See [`bus::read_alert_response()`](bus/fn.read_alert_response.html).
+    pub fn read_alert_response(&mut self) -> Result<Option<SlaveAddr>, Error<E>> {
+        self.iface.read_alert_response()
+    }
+}
+
+impl<DI, IC, CONV, MODE, E> Ads1x1x<DI, IC, CONV, MODE, alert::ConversionReady>
+where
+    DI: interface::WriteData<Error = E>,
+    IC: ic::Tier2Features,
+{
+    /// Disable comparator and conversion-ready pin (default)
+    ///
+    /// This will set the ALERT/RDY pin to high-impedance.
+    /// The thresholds keep the values required for the conversion-ready
+    /// function and must be set again before enabling the comparator.
+    pub fn disable_comparator(
+        self,
+    ) -> Result<Ads1x1x<DI, IC, CONV, MODE, alert::Disabled>, ModeChangeError<E, Self>> {
+        let config = self.config.with_comparator_disabled();
+        self.change_alert_role(config, false)
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT, E> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    DI: interface::ReadData<Error = E>,
+    IC: ic::Tier2Features,
+    CONV: conversion::ConvertThreshold<E> + ic::Resolution,
+{
+    /// Read raw comparator lower threshold
+    ///
+    /// The value is within `[2047..-2048]` for 12-bit devices (`ADS101x`)
+    /// and within `[32767..-32768]` for 16-bit devices (`ADS111x`).
+    pub fn low_threshold_raw(&mut self) -> Result<i16, Error<E>> {
+        let register_value = self.iface.read_register(Register::LOW_TH)?;
+        Ok(CONV::convert_threshold_register(register_value))
+    }
+
+    /// Read raw comparator upper threshold
+    ///
+    /// See [`low_threshold_raw()`](struct.Ads1x1x.html#method.low_threshold_raw).
+    pub fn high_threshold_raw(&mut self) -> Result<i16, Error<E>> {
+        let register_value = self.iface.read_register(Register::HIGH_TH)?;
+        Ok(CONV::convert_threshold_register(register_value))
+    }
+
+    /// Read comparator lower threshold in volts
+    ///
+    /// The raw value is converted using the configured full-scale range.
+    pub fn low_threshold_voltage(&mut self) -> Result<f32, Error<E>> {
+        let value = self.low_threshold_raw()?;
+        Ok(conversion::convert_to_volts::<CONV>(value, self.fsr))
+    }
+
+    /// Read comparator upper threshold in volts
     ///
-    /// When calling this the comparator will be disabled and the thresholds will be cleared.
-    pub fn use_alert_rdy_pin_as_ready(&mut self) -> Result<(), Error<E>> {
-        if self.config
-            != self
-                .config
-                .with_high(BF::COMP_QUE1)
-                .with_high(BF::COMP_QUE0)
-        {
-            self.disable_comparator()?;
+    /// The raw value is converted using the configured full-scale range.
+    pub fn high_threshold_voltage(&mut self) -> Result<f32, Error<E>> {
+        let value = self.high_threshold_raw()?;
+        Ok(conversion::convert_to_volts::<CONV>(value, self.fsr))
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    IC: ic::Tier2Features,
+{
+    /// Get the currently configured input voltage measurable range
+    pub fn full_scale_range(&self) -> FullScaleRange {
+        self.fsr
+    }
+
+    /// Get the currently configured comparator mode
+    pub fn comparator_mode(&self) -> ComparatorMode {
+        self.config.comparator_mode()
+    }
+
+    /// Get the currently configured comparator polarity
+    pub fn comparator_polarity(&self) -> ComparatorPolarity {
+        self.config.comparator_polarity()
+    }
+
+    /// Get the currently configured comparator latching
+    pub fn comparator_latching(&self) -> ComparatorLatching {
+        self.config.comparator_latching()
+    }
+
+    /// Get the currently configured comparator queue
+    ///
+    /// Returns `None` if the comparator is disabled.
+    pub fn comparator_queue(&self) -> Option<ComparatorQueue> {
+        self.config.comparator_queue()
+    }
+}
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    IC: ic::Tier2Features,
+    CONV: ic::Resolution,
+{
+    /// Set the full-scale range and data rate to measure a channel with, or
+    /// `None` to measure it with the current settings.
+    ///
+    /// The profile is applied in the same configuration register write
+    /// which selects the channel, both when making a one-shot measurement
+    /// and when selecting the channel in continuous-conversion mode.
+    /// Channels without profile are measured with the settings currently in
+    /// the device, which are those of the last channel measured.
+    ///
+    /// This does not communicate with the device.
+    pub fn set_channel_profile(
+        &mut self,
+        channel: ChannelSelection,
+        profile: Option<ChannelProfile<CONV::DataRate>>,
+    ) {
+        self.channel_profiles[channel.index()] = profile.map(|profile| StoredChannelProfile {
+            full_scale_range: profile.full_scale_range,
+            data_rate_bits: CONV::data_rate_bits(profile.data_rate),
+        });
+    }
+
+    /// Get the full-scale range and data rate a channel is measured with.
+    ///
+    /// See [`set_channel_profile()`](struct.Ads1x1x.html#method.set_channel_profile).
+    pub fn channel_profile(
+        &self,
+        channel: ChannelSelection,
+    ) -> Option<ChannelProfile<CONV::DataRate>> {
+        self.channel_profiles[channel.index()].map(|profile| ChannelProfile {
+            full_scale_range: profile.full_scale_range,
+            data_rate: CONV::data_rate(profile.data_rate_bits),
+        })
+    }
+}
+
+impl Config {
+    pub(crate) fn with_full_scale_range(&self, range: FullScaleRange) -> Self {
+        use crate::FullScaleRange as FSR;
+        let cfg = self.clone();
+        match range {
+            FSR::Within6_144V => cfg.with_low(BF::PGA2).with_low(BF::PGA1).with_low(BF::PGA0),
+            FSR::Within4_096V => cfg
+                .with_low(BF::PGA2)
+                .with_low(BF::PGA1)
+                .with_high(BF::PGA0),
+            FSR::Within2_048V => cfg
+                .with_low(BF::PGA2)
+                .with_high(BF::PGA1)
+                .with_low(BF::PGA0),
+            FSR::Within1_024V => cfg
+                .with_low(BF::PGA2)
+                .with_high(BF::PGA1)
+                .with_high(BF::PGA0),
+            FSR::Within0_512V => cfg
+                .with_high(BF::PGA2)
+                .with_low(BF::PGA1)
+                .with_low(BF::PGA0),
+            FSR::Within0_256V => cfg
+                .with_high(BF::PGA2)
+                .with_low(BF::PGA1)
+                .with_high(BF::PGA0),
+        }
+    }
+
+    pub(crate) fn with_comparator_mode(&self, mode: ComparatorMode) -> Self {
+        match mode {
+            ComparatorMode::Traditional => self.with_low(BF::COMP_MODE),
+            ComparatorMode::Window => self.with_high(BF::COMP_MODE),
+        }
+    }
+
+    pub(crate) fn with_comparator_polarity(&self, polarity: ComparatorPolarity) -> Self {
+        match polarity {
+            ComparatorPolarity::ActiveLow => self.with_low(BF::COMP_POL),
+            ComparatorPolarity::ActiveHigh => self.with_high(BF::COMP_POL),
+        }
+    }
+
+    pub(crate) fn with_comparator_latching(&self, latching: ComparatorLatching) -> Self {
+        match latching {
+            ComparatorLatching::Nonlatching => self.with_low(BF::COMP_LAT),
+            ComparatorLatching::Latching => self.with_high(BF::COMP_LAT),
+        }
+    }
+
+    pub(crate) fn with_comparator_queue(&self, queue: ComparatorQueue) -> Self {
+        match queue {
+            ComparatorQueue::One => self.with_low(BF::COMP_QUE1).with_low(BF::COMP_QUE0),
+            ComparatorQueue::Two => self.with_low(BF::COMP_QUE1).with_high(BF::COMP_QUE0),
+            ComparatorQueue::Four => self.with_high(BF::COMP_QUE1).with_low(BF::COMP_QUE0),
+        }
+    }
+
+    pub(crate) fn with_comparator_disabled(&self) -> Self {
+        self.with_high(BF::COMP_QUE1).with_high(BF::COMP_QUE0)
+    }
+
+    pub(crate) fn full_scale_range(&self) -> FullScaleRange {
+        use crate::FullScaleRange as FSR;
+        match (self.bits & (BF::PGA2 | BF::PGA1 | BF::PGA0)) >> 9 {
+            0b000 => FSR::Within6_144V,
+            0b001 => FSR::Within4_096V,
+            0b010 => FSR::Within2_048V,
+            0b011 => FSR::Within1_024V,
+            0b100 => FSR::Within0_512V,
+            _ => FSR::Within0_256V,
+        }
+    }
+
+    pub(crate) fn comparator_mode(&self) -> ComparatorMode {
+        if self.is_high(BF::COMP_MODE) {
+            ComparatorMode::Window
+        } else {
+            ComparatorMode::Traditional
+        }
+    }
+
+    pub(crate) fn comparator_polarity(&self) -> ComparatorPolarity {
+        if self.is_high(BF::COMP_POL) {
+            ComparatorPolarity::ActiveHigh
+        } else {
+            ComparatorPolarity::ActiveLow
+        }
+    }
+
+    pub(crate) fn comparator_latching(&self) -> ComparatorLatching {
+        if self.is_high(BF::COMP_LAT) {
+            ComparatorLatching::Latching
+        } else {
+            ComparatorLatching::Nonlatching
+        }
+    }
+
+    pub(crate) fn comparator_queue(&self) -> Option<ComparatorQueue> {
+        match (self.is_high(BF::COMP_QUE1), self.is_high(BF::COMP_QUE0)) {
+            (false, false) => Some(ComparatorQueue::One),
+            (false, true) => Some(ComparatorQueue::Two),
+            (true, false) => Some(ComparatorQueue::Four),
+            (true, true) => None,
         }
-        self.iface.write_register(Register::HIGH_TH, 0x8000)?;
-        self.iface.write_register(Register::LOW_TH, 0)
     }
 }
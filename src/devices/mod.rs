@@ -1,9 +1,14 @@
-#[derive(Debug, Clone, Copy)]
-enum OperatingMode {
-    OneShot,
-    Continuous,
-}
+// The type-state transitions return the driver with changed type parameters.
+#![allow(clippy::type_complexity)]
 
+// The `async` feature requires Rust 1.75.
+#[cfg(feature = "async")]
+#[clippy::msrv = "1.75"]
+mod asynch;
+mod autorange;
+mod builder;
 mod common;
 mod features;
 mod mode;
+mod sequence;
+mod stream;
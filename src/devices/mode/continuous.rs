@@ -1,13 +1,12 @@
 //! Continuous measurement mode
 
 use crate::{
-    channels::ChannelSelection, conversion, devices::OperatingMode, interface, mode, Ads1x1x,
-    Error, ModeChangeError, Register,
+    channels::ChannelSelection, conversion, ic, interface, mode, types::FreshSample, Ads1x1x,
+    ChannelScan, Error, Measurement, ModeChangeError, OperatingMode, Register,
 };
-use core::marker::PhantomData;
 use embedded_hal::adc;
 
-impl<DI, IC, CONV, E> Ads1x1x<DI, IC, CONV, mode::Continuous>
+impl<DI, IC, CONV, ALERT, E> Ads1x1x<DI, IC, CONV, mode::Continuous, ALERT>
 where
     DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
     CONV: conversion::ConvertMeasurement,
@@ -15,27 +14,58 @@ where
     /// Change operating mode to OneShot
     pub fn into_one_shot(
         mut self,
-    ) -> Result<Ads1x1x<DI, IC, CONV, mode::OneShot>, ModeChangeError<E, Self>> {
-        if let Err(Error::I2C(e)) = self.set_operating_mode(OperatingMode::OneShot) {
+    ) -> Result<Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>, ModeChangeError<E, Self>> {
+        if let Err(Error::I2C(e, _)) = self.set_operating_mode(OperatingMode::OneShot) {
             return Err(ModeChangeError::I2C(e, self));
         }
-        Ok(Ads1x1x {
-            iface: self.iface,
-            config: self.config,
-            fsr: self.fsr,
-            a_conversion_was_started: false,
-            _conv: PhantomData,
-            _ic: PhantomData,
-            _mode: PhantomData,
-        })
+        Ok(self.into_mode(false))
     }
 
     /// Read the most recent measurement
     pub fn read(&mut self) -> Result<i16, Error<E>> {
+        let value = self.read_unchecked()?;
+        self.checked_measurement(value)
+    }
+
+    /// Read the most recent measurement flagged with whether it was clipped
+    /// at the limits of the full-scale range
+    pub fn read_measurement(&mut self) -> Result<Measurement, Error<E>> {
+        let value = self.read()?;
+        Ok(Self::flagged_measurement(value))
+    }
+
+    /// Read the most recent measurement regardless of whether clipped
+    /// measurements are returned as errors.
+    pub(crate) fn read_unchecked(&mut self) -> Result<i16, Error<E>> {
         let value = self.iface.read_register(Register::CONVERSION)?;
         Ok(CONV::convert_measurement(value))
     }
 
+    /// Read the most recent measurement in volts
+    ///
+    /// The measurement is scaled using the configured full-scale range,
+    /// which is fixed at ±2.048 V in the ADS1x13.
+    /// See [`FullScaleRange`](enum.FullScaleRange.html).
+    pub fn read_voltage(&mut self) -> Result<f32, Error<E>>
+    where
+        CONV: ic::Resolution,
+    {
+        let value = self.read()?;
+        Ok(conversion::convert_to_volts::<CONV>(value, self.fsr))
+    }
+
+    /// Read the most recent measurement in millivolts, rounded to the
+    /// nearest millivolt.
+    ///
+    /// This uses integer arithmetic only.
+    pub fn read_millivolts(&mut self) -> Result<i32, Error<E>>
+    where
+        CONV: ic::Resolution,
+    {
+        let value = self.read()?;
+        Ok(conversion::convert_to_millivolts::<CONV>(value, self.fsr))
+    }
+
     /// Select the channel for measurements.
     ///
     /// Note that when changing the channel in continuous conversion mode, the
@@ -43,11 +73,130 @@ where
     /// The following conversions will use the new channel configuration.
     pub fn select_channel<CH>(&mut self, _channel: &mut CH) -> Result<(), Error<E>>
     where
-        CH: adc::Channel<Ads1x1x<DI, IC, CONV, mode::OneShot>, ID = ChannelSelection>,
+        CH: adc::Channel<Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>, ID = ChannelSelection>,
     {
-        let config = self.config.with_mux_bits(CH::channel());
+        let (config, fsr) = self.channel_config(CH::channel());
         self.iface.write_register(Register::CONFIG, config.bits)?;
         self.config = config;
+        self.fsr = fsr;
         Ok(())
     }
 }
+
+impl<DI, IC, CONV, ALERT, E> Ads1x1x<DI, IC, CONV, mode::Continuous, ALERT>
+where
+    DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Read the most recent measurement if it was not returned by this
+    /// method before.
+    ///
+    /// `now_us` is the current time in microseconds from a monotonic clock,
+    /// which may wrap around. Successive calls must be less than 35 minutes
+    /// apart.
+    ///
+    /// Freshness is derived from the conversion time corresponding to the
+    /// configured data rate: a measurement is only returned once a full
+    /// conversion has finished since the previous one was returned.
+    /// After entering continuous-conversion mode, on the first call and after
+    /// the configuration was changed, for example by selecting a different
+    /// channel, the conversion which was ongoing is discarded as well.
+    ///
+    /// Returns `nb::Error::WouldBlock` until a fresh measurement is available.
+    pub fn read_new(&mut self, now_us: u32) -> nb::Result<i16, Error<E>> {
+        if self.fresh_sample_age_us(now_us).is_some() {
+            let value = self.read_unchecked().map_err(nb::Error::Other)?;
+            self.mark_sample_returned(now_us);
+            self.checked_measurement(value).map_err(nb::Error::Other)
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
+    /// Measure the next channel of a scan and return it together with the
+    /// measurement.
+    ///
+    /// If the channel differs from the selected one, it is selected and the
+    /// conversion which was ongoing during the switch, whose result stems
+    /// from the previous channel, is discarded by waiting for the
+    /// conversion after it to finish.
+    /// Otherwise this waits for a new conversion on the selected channel.
+    /// The waiting uses the conversion times corresponding to the
+    /// configured data rates.
+    ///
+    /// Channel profiles are applied when switching channels.
+    /// See [`set_channel_profile()`](struct.Ads1x1x.html#method.set_channel_profile).
+    ///
+    /// Returns `Error::InvalidInputData` if the scan has no channels.
+    pub fn read_scan<D, const N: usize>(
+        &mut self,
+        scan: &mut ChannelScan<N>,
+        delay: &mut D,
+    ) -> Result<(ChannelSelection, i16), Error<E>>
+    where
+        D: interface::Delay,
+    {
+        let channel = scan.next_channel().ok_or(Error::InvalidInputData)?;
+        let (config, fsr) = self.channel_config(channel);
+        let mut wait_us = config.conversion_time_us::<CONV>();
+        if config != self.config {
+            wait_us += self.config.conversion_time_us::<CONV>();
+            self.iface.write_register(Register::CONFIG, config.bits)?;
+            self.config = config;
+            self.fsr = fsr;
+        }
+        delay.wait_us(wait_us);
+        let value = self.read_unchecked()?;
+        scan.advance();
+        Ok((channel, self.checked_measurement(value)?))
+    }
+}
+
+impl<DI, IC, CONV, ALERT> Ads1x1x<DI, IC, CONV, mode::Continuous, ALERT>
+where
+    CONV: ic::Resolution,
+{
+    /// Time in microseconds for which a fresh conversion result has been
+    /// available at the given time, `None` if there is none.
+    ///
+    /// On the first call after entering continuous-conversion mode or
+    /// changing the configuration, this starts waiting for a fresh result.
+    pub(crate) fn fresh_sample_age_us(&mut self, now_us: u32) -> Option<u32> {
+        let conversion_time_us = self.config.conversion_time_us::<CONV>();
+        match &self.fresh_sample {
+            Some(fresh) if fresh.config == self.config => {
+                // Interpreting the wrapped difference as signed tells
+                // whether the time has passed.
+                let age_us = now_us.wrapping_sub(fresh.at_us);
+                if age_us as i32 >= 0 {
+                    Some(age_us)
+                } else {
+                    None
+                }
+            }
+            previous => {
+                // The conversion ongoing during the change may still use the
+                // previous configuration.
+                let ongoing_us = previous
+                    .as_ref()
+                    .map_or(0, |fresh| fresh.config.conversion_time_us::<CONV>());
+                self.fresh_sample = Some(FreshSample {
+                    config: self.config.clone(),
+                    at_us: now_us
+                        .wrapping_add(ongoing_us)
+                        .wrapping_add(conversion_time_us),
+                });
+                None
+            }
+        }
+    }
+
+    /// Record that the conversion result available at the given time was
+    /// returned, so the next one is fresh after another conversion.
+    pub(crate) fn mark_sample_returned(&mut self, now_us: u32) {
+        self.fresh_sample = Some(FreshSample {
+            config: self.config.clone(),
+            at_us: now_us.wrapping_add(self.config.conversion_time_us::<CONV>()),
+        });
+    }
+}
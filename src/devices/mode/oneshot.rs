@@ -1,12 +1,12 @@
 //! Common functions
 use crate::{
-    conversion, devices::OperatingMode, interface, mode, Ads1x1x, BitFlags, ChannelSelection,
-    Config, DynamicOneShot, Error, ModeChangeError, Register,
+    alert, conversion, ic, interface, mode, Ads1x1x, BitFlags, ChannelSelection,
+    ComparatorPolarity, Config, DynamicOneShot, Error, Measurement, ModeChangeError, OperatingMode,
+    Register, CONVERSION_POLL_RETRIES,
 };
-use core::marker::PhantomData;
 use embedded_hal::adc;
 
-impl<DI, IC, CONV, E> Ads1x1x<DI, IC, CONV, mode::OneShot>
+impl<DI, IC, CONV, ALERT, E> Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>
 where
     DI: interface::WriteData<Error = E> + interface::ReadData<Error = E>,
     CONV: conversion::ConvertMeasurement,
@@ -14,33 +14,265 @@ where
     /// Change operating mode to Continuous
     pub fn into_continuous(
         mut self,
-    ) -> Result<Ads1x1x<DI, IC, CONV, mode::Continuous>, ModeChangeError<E, Self>> {
-        if let Err(Error::I2C(e)) = self.set_operating_mode(OperatingMode::Continuous) {
+    ) -> Result<Ads1x1x<DI, IC, CONV, mode::Continuous, ALERT>, ModeChangeError<E, Self>> {
+        if let Err(Error::I2C(e, _)) = self.set_operating_mode(OperatingMode::Continuous) {
             return Err(ModeChangeError::I2C(e, self));
         }
-        Ok(Ads1x1x {
-            iface: self.iface,
-            config: self.config,
-            fsr: self.fsr,
-            a_conversion_was_started: true,
-            _conv: PhantomData,
-            _ic: PhantomData,
-            _mode: PhantomData,
-        })
+        Ok(self.into_mode(true))
     }
 
-    fn trigger_measurement(&mut self, config: &Config) -> Result<(), Error<E>> {
-        let config = config.with_high(BitFlags::OS);
-        self.iface.write_register(Register::CONFIG, config.bits)
+    pub(crate) fn trigger_measurement(&mut self, config: &Config) -> Result<(), Error<E>> {
+        let bits = self.trigger_bits(config);
+        self.iface.write_register(Register::CONFIG, bits)
+    }
+
+    /// Read whether the conversion started by this driver is in progress.
+    ///
+    /// See `conversion_status()`.
+    pub(crate) fn is_conversion_in_progress(&mut self) -> Result<bool, Error<E>> {
+        let device_config = Config {
+            bits: self.iface.read_register(Register::CONFIG)?,
+        };
+        let status = self.conversion_status(&device_config);
+        self.retrigger_on_error(status)
+    }
+
+    /// Count a poll of a conversion which has not finished yet.
+    ///
+    /// See `count_poll()`.
+    pub(crate) fn check_poll_limit(&mut self, limit: Option<u32>) -> Result<(), Error<E>> {
+        let result = self.count_poll(limit);
+        self.retrigger_on_error(result)
+    }
+
+    /// Write the cached configuration to the device again if the result is
+    /// an error, triggering a new conversion.
+    fn retrigger_on_error<T>(&mut self, result: Result<T, Error<E>>) -> Result<T, Error<E>> {
+        if result.is_err() {
+            let config = self.config.clone();
+            self.trigger_measurement(&config)?;
+            self.a_conversion_was_started = true;
+        }
+        result
+    }
+}
+
+impl<DI, IC, CONV, ALERT> Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT> {
+    /// Configuration register value triggering a conversion with the
+    /// configuration.
+    ///
+    /// This resets the count of polls of the conversion.
+    pub(crate) fn trigger_bits(&mut self, config: &Config) -> u16 {
+        self.conversion_polls = 0;
+        config.with_high(BitFlags::OS).bits
+    }
+
+    /// Decide from the configuration read from the device whether the
+    /// conversion started by this driver is in progress.
+    ///
+    /// If the device reports that no conversion is in progress but its
+    /// configuration differs from the cached one, the device was most likely
+    /// reset (e.g. by a general-call reset or a brown-out) and the conversion
+    /// register does not contain the requested result. In that case
+    /// `Error::ConfigurationChanged` is returned and the caller must write
+    /// the cached configuration to the device again, triggering a new
+    /// conversion.
+    pub(crate) fn conversion_status<E>(&self, device_config: &Config) -> Result<bool, Error<E>> {
+        if !device_config.is_high(BitFlags::OS) {
+            return Ok(true);
+        }
+        if self.a_conversion_was_started && *device_config != self.config.with_high(BitFlags::OS) {
+            return Err(Error::ConfigurationChanged);
+        }
+        Ok(false)
+    }
+
+    /// Count a poll of a conversion which has not finished yet.
+    ///
+    /// Once the poll limit is reached, `Error::Timeout` is returned and the
+    /// caller must write the cached configuration to the device again,
+    /// triggering a new conversion.
+    pub(crate) fn count_poll<E>(&mut self, limit: Option<u32>) -> Result<(), Error<E>> {
+        self.conversion_polls = self.conversion_polls.saturating_add(1);
+        match limit {
+            Some(limit) if self.conversion_polls >= limit => Err(Error::Timeout),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<DI, IC, CONV, ALERT, E> Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>
+where
+    DI: interface::WriteData<Error = E> + interface::ReadData<Error = E>,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Make a measurement on the specified channel waiting for the conversion
+    /// to finish using the provided delay.
+    ///
+    /// This waits for the conversion time corresponding to the configured
+    /// data rate before checking whether the conversion has finished, which
+    /// avoids polling the device while the conversion is ongoing.
+    /// If the conversion has not finished by then, the device is polled up to
+    /// the configured poll limit (16 times by default) and `Error::Timeout`
+    /// is returned if it still has not finished.
+    /// In that case a new conversion is triggered, whose result is awaited
+    /// on the next call.
+    /// See [`set_conversion_poll_limit()`](struct.Ads1x1x.html#method.set_conversion_poll_limit).
+    pub fn read_blocking<CH, D>(
+        &mut self,
+        _channel: &mut CH,
+        delay: &mut D,
+    ) -> Result<i16, Error<E>>
+    where
+        CH: adc::Channel<Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>, ID = ChannelSelection>,
+        D: interface::Delay,
+    {
+        if self.a_conversion_was_started {
+            // Writing the OS bit has no effect until a previously started
+            // conversion is finished.
+            self.wait_for_conversion(delay)?;
+        }
+        let (config, fsr) = self.channel_config(CH::channel());
+        self.trigger_measurement(&config)?;
+        self.config = config;
+        self.fsr = fsr;
+        self.a_conversion_was_started = true;
+        self.wait_for_conversion(delay)?;
+        let value = self.iface.read_register(Register::CONVERSION)?;
+        self.a_conversion_was_started = false;
+        self.checked_measurement(CONV::convert_measurement(value))
+    }
+
+    /// Wait for the conversion time and then poll the device until the
+    /// conversion has finished.
+    pub(crate) fn wait_for_conversion<D: interface::Delay>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<E>> {
+        let conversion_time_us = self.config.conversion_time_us::<CONV>();
+        let limit = self
+            .conversion_poll_limit
+            .unwrap_or(CONVERSION_POLL_RETRIES);
+        delay.wait_us(conversion_time_us);
+        while self.is_conversion_in_progress()? {
+            self.check_poll_limit(Some(limit))?;
+            delay.wait_us(conversion_time_us / 8 + 1);
+        }
+        Ok(())
+    }
+
+    /// Request that the ADC begin a conversion on the specified channel and
+    /// return the result in volts.
+    ///
+    /// The measurement is scaled using the configured full-scale range,
+    /// which is fixed at ±2.048 V in the ADS1x13.
+    /// See [`FullScaleRange`](enum.FullScaleRange.html).
+    ///
+    /// Returns `nb::Error::WouldBlock` while a measurement is in progress.
+    pub fn read_voltage<CH>(&mut self, _channel: &mut CH) -> nb::Result<f32, Error<E>>
+    where
+        CH: adc::Channel<Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>, ID = ChannelSelection>,
+    {
+        let value = <Self as DynamicOneShot>::read(self, CH::channel())?;
+        Ok(conversion::convert_to_volts::<CONV>(value, self.fsr))
+    }
+
+    /// Request that the ADC begin a conversion on the specified channel and
+    /// return the result in millivolts, rounded to the nearest millivolt.
+    ///
+    /// This uses integer arithmetic only.
+    /// See [`read_voltage()`](struct.Ads1x1x.html#method.read_voltage).
+    pub fn read_millivolts<CH>(&mut self, _channel: &mut CH) -> nb::Result<i32, Error<E>>
+    where
+        CH: adc::Channel<Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>, ID = ChannelSelection>,
+    {
+        let value = <Self as DynamicOneShot>::read(self, CH::channel())?;
+        Ok(conversion::convert_to_millivolts::<CONV>(value, self.fsr))
+    }
+
+    /// Request that the ADC begin a conversion on the specified channel and
+    /// return the result flagged with whether it was clipped at the limits
+    /// of the full-scale range.
+    ///
+    /// Returns `nb::Error::WouldBlock` while a measurement is in progress.
+    pub fn read_measurement<CH>(&mut self, _channel: &mut CH) -> nb::Result<Measurement, Error<E>>
+    where
+        CH: adc::Channel<Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>, ID = ChannelSelection>,
+    {
+        let value = <Self as DynamicOneShot>::read(self, CH::channel())?;
+        Ok(Self::flagged_measurement(value))
     }
 }
 
-impl<DI, IC, CONV, E, CH> adc::OneShot<Ads1x1x<DI, IC, CONV, mode::OneShot>, i16, CH>
-    for Ads1x1x<DI, IC, CONV, mode::OneShot>
+impl<DI, IC, CONV, E> Ads1x1x<DI, IC, CONV, mode::OneShot, alert::ConversionReady>
+where
+    DI: interface::WriteData<Error = E> + interface::ReadData<Error = E>,
+    CONV: conversion::ConvertMeasurement,
+{
+    /// Request a measurement on the specified channel using the ALERT/RDY pin
+    /// to detect the end of the conversion.
+    ///
+    /// `pin` must be an input connected to the ALERT/RDY pin of the device.
+    /// In contrast to [`read()`][read_os], the configuration register is not
+    /// polled while the conversion is in progress.
+    ///
+    /// The first call triggers the conversion without looking at the pin.
+    /// Returns `nb::Error::WouldBlock` while a measurement is in progress.
+    ///
+    /// Each check of the pin counts against the poll limit. If the pin does
+    /// not signal the end of the conversion within it, `Error::Timeout` is
+    /// returned and a new measurement is triggered.
+    /// See [`set_conversion_poll_limit()`](struct.Ads1x1x.html#method.set_conversion_poll_limit).
+    ///
+    /// [read_os]: struct.Ads1x1x.html#method.read
+    pub fn read_with_ready_pin<CH, P>(
+        &mut self,
+        _channel: &mut CH,
+        pin: &mut P,
+    ) -> nb::Result<i16, Error<E>>
+    where
+        CH: adc::Channel<
+            Ads1x1x<DI, IC, CONV, mode::OneShot, alert::ConversionReady>,
+            ID = ChannelSelection,
+        >,
+        P: interface::ReadyPin,
+    {
+        let (config, fsr) = self.channel_config(CH::channel());
+        if self.a_conversion_was_started {
+            // ALERT/RDY stays deasserted until a conversion finishes, so the
+            // pin is only meaningful once one has been started.
+            let ready_level = self.config.comparator_polarity() == ComparatorPolarity::ActiveHigh;
+            if pin.is_pin_high().map_err(nb::Error::Other)? != ready_level {
+                self.check_poll_limit(self.conversion_poll_limit)
+                    .map_err(nb::Error::Other)?;
+                return Err(nb::Error::WouldBlock);
+            }
+        }
+        if self.a_conversion_was_started && self.config == config {
+            let value = self
+                .iface
+                .read_register(Register::CONVERSION)
+                .map_err(nb::Error::Other)?;
+            self.a_conversion_was_started = false;
+            return self
+                .checked_measurement(CONV::convert_measurement(value))
+                .map_err(nb::Error::Other);
+        }
+        self.trigger_measurement(&config)
+            .map_err(nb::Error::Other)?;
+        self.config = config;
+        self.fsr = fsr;
+        self.a_conversion_was_started = true;
+        Err(nb::Error::WouldBlock)
+    }
+}
+
+impl<DI, IC, CONV, ALERT, E, CH> adc::OneShot<Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>, i16, CH>
+    for Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>
 where
     DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
     CONV: conversion::ConvertMeasurement,
-    CH: adc::Channel<Ads1x1x<DI, IC, CONV, mode::OneShot>, ID = ChannelSelection>,
+    CH: adc::Channel<Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>, ID = ChannelSelection>,
 {
     type Error = Error<E>;
 
@@ -57,12 +289,23 @@ where
     /// In case a measurement was requested and after is it is finished a
     /// measurement on a different channel is requested, a new measurement on
     /// using the new channel selection is triggered.
+    ///
+    /// If a poll limit is set and the measurement does not finish within it,
+    /// `Error::Timeout` is returned and a new measurement is triggered.
+    /// See [`set_conversion_poll_limit()`](struct.Ads1x1x.html#method.set_conversion_poll_limit).
+    ///
+    /// If the device configuration no longer matches the cached one once the
+    /// conversion has finished, the device was most likely reset and
+    /// `Error::ConfigurationChanged` is returned instead of the stale
+    /// conversion result. A new measurement is then triggered.
+    /// A reset cannot be detected if the cached configuration is the
+    /// power-up configuration of the device.
     fn read(&mut self, _channel: &mut CH) -> nb::Result<i16, Self::Error> {
         <Self as DynamicOneShot>::read(self, CH::channel())
     }
 }
 
-impl<DI, IC, CONV, E> DynamicOneShot for Ads1x1x<DI, IC, CONV, mode::OneShot>
+impl<DI, IC, CONV, ALERT, E> DynamicOneShot for Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>
 where
     DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
     CONV: conversion::ConvertMeasurement,
@@ -70,26 +313,29 @@ where
     type Error = Error<E>;
 
     fn read(&mut self, channel: ChannelSelection) -> nb::Result<i16, Self::Error> {
-        if self
-            .is_measurement_in_progress()
-            .map_err(nb::Error::Other)?
-        {
+        if self.is_conversion_in_progress().map_err(nb::Error::Other)? {
+            if self.a_conversion_was_started {
+                self.check_poll_limit(self.conversion_poll_limit)
+                    .map_err(nb::Error::Other)?;
+            }
             return Err(nb::Error::WouldBlock);
         }
-        let same_channel = self.config == self.config.with_mux_bits(channel);
-        if self.a_conversion_was_started && same_channel {
+        let (config, fsr) = self.channel_config(channel);
+        if self.a_conversion_was_started && self.config == config {
             // result is ready
             let value = self
                 .iface
                 .read_register(Register::CONVERSION)
                 .map_err(nb::Error::Other)?;
             self.a_conversion_was_started = false;
-            return Ok(CONV::convert_measurement(value));
+            return self
+                .checked_measurement(CONV::convert_measurement(value))
+                .map_err(nb::Error::Other);
         }
-        let config = self.config.with_mux_bits(channel);
         self.trigger_measurement(&config)
             .map_err(nb::Error::Other)?;
         self.config = config;
+        self.fsr = fsr;
         self.a_conversion_was_started = true;
         Err(nb::Error::WouldBlock)
     }
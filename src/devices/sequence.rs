@@ -0,0 +1,135 @@
+//! Sequences of one-shot measurements
+
+use crate::{
+    conversion, ic, interface, mode, Ads1x1x, Config, Error, FullScaleRange, Register, Sequence,
+    SequenceStep,
+};
+
+impl<DI, IC, CONV, MODE, ALERT> Ads1x1x<DI, IC, CONV, MODE, ALERT>
+where
+    IC: ic::Features,
+{
+    /// Configuration for measuring the given sequence step and the
+    /// full-scale range it results in.
+    ///
+    /// The full-scale range of the step takes precedence over the one of
+    /// the channel profile.
+    pub(crate) fn sequence_step_config<E>(
+        &self,
+        step: &SequenceStep,
+    ) -> Result<(Config, FullScaleRange), Error<E>> {
+        let (config, fsr) = self.channel_config(step.channel);
+        match step.full_scale_range {
+            None => Ok((config, fsr)),
+            Some(range) if IC::TIER2 => Ok((config.with_full_scale_range(range), range)),
+            Some(_) => Err(Error::InvalidInputData),
+        }
+    }
+}
+
+impl<DI, IC, CONV, ALERT, E> Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>
+where
+    DI: interface::WriteData<Error = E> + interface::ReadData<Error = E>,
+    IC: ic::Features,
+    CONV: conversion::ConvertMeasurement,
+{
+    pub(super) fn start_sequence_step(&mut self, step: &SequenceStep) -> Result<(), Error<E>> {
+        let (config, fsr) = self.sequence_step_config(step)?;
+        self.trigger_measurement(&config)?;
+        self.config = config;
+        self.fsr = fsr;
+        self.a_conversion_was_started = true;
+        Ok(())
+    }
+
+    /// Read the result of a step regardless of whether clipped measurements
+    /// are returned as errors.
+    pub(super) fn read_sequence_step(&mut self) -> Result<i16, Error<E>> {
+        let value = self.iface.read_register(Register::CONVERSION)?;
+        self.a_conversion_was_started = false;
+        Ok(CONV::convert_measurement(value))
+    }
+
+    /// Measure a sequence of channels back-to-back, each optionally with its
+    /// own full-scale range.
+    ///
+    /// Each measurement is triggered with a single write of the
+    /// configuration register, which is then polled until the conversion
+    /// has finished.
+    /// The progress is stored in the sequence, so this can be called
+    /// repeatedly until the results of all steps are returned.
+    /// The configuration of the last step remains selected afterwards.
+    ///
+    /// Returns `nb::Error::WouldBlock` while a measurement is in progress.
+    /// Returns `Error::InvalidInputData` if a step sets a full-scale range
+    /// in an ADS1x13.
+    /// If a measurement is returned as `Error::OutOfRange`, the sequence
+    /// starts over on the next call.
+    pub fn read_sequence<const N: usize>(
+        &mut self,
+        sequence: &mut Sequence<N>,
+    ) -> nb::Result<[i16; N], Error<E>> {
+        if N == 0 {
+            return Ok(sequence.results);
+        }
+        if self.a_conversion_was_started
+            && self.is_conversion_in_progress().map_err(nb::Error::Other)?
+        {
+            self.check_poll_limit(self.conversion_poll_limit)
+                .map_err(nb::Error::Other)?;
+            return Err(nb::Error::WouldBlock);
+        }
+        if sequence.started {
+            let value = self.read_sequence_step().map_err(nb::Error::Other)?;
+            if let Err(e) = self.checked_measurement(value) {
+                sequence.reset();
+                return Err(nb::Error::Other(e));
+            }
+            sequence.results[sequence.next] = value;
+            sequence.next += 1;
+            if sequence.next == N {
+                sequence.reset();
+                return Ok(sequence.results);
+            }
+        }
+        if let Err(e) = self.start_sequence_step(&sequence.steps[sequence.next]) {
+            sequence.reset();
+            return Err(nb::Error::Other(e));
+        }
+        sequence.started = true;
+        Err(nb::Error::WouldBlock)
+    }
+}
+
+impl<DI, IC, CONV, ALERT, E> Ads1x1x<DI, IC, CONV, mode::OneShot, ALERT>
+where
+    DI: interface::WriteData<Error = E> + interface::ReadData<Error = E>,
+    IC: ic::Features,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Measure a sequence of channels back-to-back waiting for each
+    /// conversion to finish using the provided delay.
+    ///
+    /// See [`read_sequence()`](struct.Ads1x1x.html#method.read_sequence)
+    /// and [`read_blocking()`](struct.Ads1x1x.html#method.read_blocking).
+    pub fn read_sequence_blocking<D, const N: usize>(
+        &mut self,
+        sequence: &Sequence<N>,
+        delay: &mut D,
+    ) -> Result<[i16; N], Error<E>>
+    where
+        D: interface::Delay,
+    {
+        if self.a_conversion_was_started {
+            self.wait_for_conversion(delay)?;
+        }
+        let mut results = [0; N];
+        for (result, step) in results.iter_mut().zip(sequence.steps.iter()) {
+            self.start_sequence_step(step)?;
+            self.wait_for_conversion(delay)?;
+            let value = self.read_sequence_step()?;
+            *result = self.checked_measurement(value)?;
+        }
+        Ok(results)
+    }
+}
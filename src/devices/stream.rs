@@ -0,0 +1,71 @@
+//! Streaming of continuous-conversion mode measurements
+
+use crate::{
+    alert, conversion, ic, interface, mode,
+    stream::{Sample, SampleSink, Stream},
+    Ads1x1x, Error,
+};
+
+impl<DI, IC, CONV, E> Ads1x1x<DI, IC, CONV, mode::Continuous, alert::ConversionReady>
+where
+    DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
+    CONV: conversion::ConvertMeasurement,
+{
+    /// Read the measurement which just finished into the stream.
+    ///
+    /// This must be called on each conversion-ready pulse of the ALERT/RDY
+    /// pin, for example from its interrupt handler.
+    /// `timestamp_us` is stored together with the measurement.
+    ///
+    /// If the sample sink is full, the measurement is counted as overrun.
+    pub fn stream_ready<S: SampleSink>(
+        &mut self,
+        stream: &mut Stream<S>,
+        timestamp_us: Option<u32>,
+    ) -> Result<(), Error<E>> {
+        let value = self.read_unchecked()?;
+        let value = self.checked_measurement(value)?;
+        stream.push(Sample {
+            value,
+            timestamp_us,
+        });
+        Ok(())
+    }
+}
+
+impl<DI, IC, CONV, ALERT, E> Ads1x1x<DI, IC, CONV, mode::Continuous, ALERT>
+where
+    DI: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
+    CONV: conversion::ConvertMeasurement + ic::Resolution,
+{
+    /// Read a fresh measurement into the stream if there is one.
+    ///
+    /// This must be called periodically, at least at the configured data
+    /// rate to avoid losing samples. `now_us` is the current time in
+    /// microseconds as for [`read_new()`](struct.Ads1x1x.html#method.read_new)
+    /// and is stored together with the measurement.
+    ///
+    /// Conversions which finished without being read since the previous
+    /// call and measurements not fitting into the sample sink are counted
+    /// as overruns.
+    ///
+    /// Returns `nb::Error::WouldBlock` if there is no fresh measurement.
+    pub fn stream_poll<S: SampleSink>(
+        &mut self,
+        stream: &mut Stream<S>,
+        now_us: u32,
+    ) -> nb::Result<(), Error<E>> {
+        let age_us = self
+            .fresh_sample_age_us(now_us)
+            .ok_or(nb::Error::WouldBlock)?;
+        let value = self.read_unchecked().map_err(nb::Error::Other)?;
+        stream.add_overruns(age_us / self.config.conversion_period_us::<CONV>());
+        self.mark_sample_returned(now_us);
+        let value = self.checked_measurement(value).map_err(nb::Error::Other)?;
+        stream.push(Sample {
+            value,
+            timestamp_us: Some(now_us),
+        });
+        Ok(())
+    }
+}
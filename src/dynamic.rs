@@ -0,0 +1,220 @@
+//! Driver for a device variant only known at runtime.
+
+use crate::{
+    bus::Resolution, conversion, ic, interface, interface::I2cInterface, mode, Ads1x1x,
+    ChannelSelection, DynamicOneShot, Error, FullScaleRange, SlaveAddr, DEVICE_BASE_ADDRESS,
+};
+
+/// Device variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    /// ADS1013: 12-bit, no PGA, no input multiplexer
+    Ads1013,
+    /// ADS1113: 16-bit, no PGA, no input multiplexer
+    Ads1113,
+    /// ADS1014: 12-bit, PGA and comparator, no input multiplexer
+    Ads1014,
+    /// ADS1114: 16-bit, PGA and comparator, no input multiplexer
+    Ads1114,
+    /// ADS1015: 12-bit, PGA, comparator and input multiplexer
+    Ads1015,
+    /// ADS1115: 16-bit, PGA, comparator and input multiplexer
+    Ads1115,
+}
+
+impl Variant {
+    /// Resolution of this variant
+    pub fn resolution(self) -> Resolution {
+        match self {
+            Variant::Ads1013 | Variant::Ads1014 | Variant::Ads1015 => Resolution::Bits12,
+            Variant::Ads1113 | Variant::Ads1114 | Variant::Ads1115 => Resolution::Bits16,
+        }
+    }
+
+    /// Whether this variant has a programmable gain amplifier and comparator
+    pub fn has_tier2_features(self) -> bool {
+        !matches!(self, Variant::Ads1013 | Variant::Ads1113)
+    }
+
+    /// Whether this variant has an input multiplexer
+    pub fn has_input_multiplexer(self) -> bool {
+        matches!(self, Variant::Ads1015 | Variant::Ads1115)
+    }
+
+    /// Get the variant with the given resolution and features
+    ///
+    /// Devices without the tier 2 features have no input multiplexer either,
+    /// so `input_multiplexer` is ignored if `tier2_features` is `false`.
+    /// The resolution can be detected with
+    /// [`bus::detect_resolution()`](bus/fn.detect_resolution.html).
+    pub fn from_features(
+        resolution: Resolution,
+        tier2_features: bool,
+        input_multiplexer: bool,
+    ) -> Self {
+        match (resolution, tier2_features, input_multiplexer) {
+            (Resolution::Bits12, false, _) => Variant::Ads1013,
+            (Resolution::Bits16, false, _) => Variant::Ads1113,
+            (Resolution::Bits12, true, false) => Variant::Ads1014,
+            (Resolution::Bits16, true, false) => Variant::Ads1114,
+            (Resolution::Bits12, true, true) => Variant::Ads1015,
+            (Resolution::Bits16, true, true) => Variant::Ads1115,
+        }
+    }
+}
+
+/// ADS1x1x driver for a device variant only known at runtime
+///
+/// This wraps the driver instances of the six device variants in one-shot
+/// mode so that a single firmware can support all of them.
+/// The resolution of the device can be detected with
+/// [`bus::detect_resolution()`](bus/fn.detect_resolution.html).
+///
+/// Measurements are made through the [`DynamicOneShot`](trait.DynamicOneShot.html)
+/// trait. For variant-specific functionality, match on the wrapped driver.
+pub enum DynamicAds1x1x<I2C> {
+    /// ADS1013 driver
+    Ads1013(Ads1x1x<I2cInterface<I2C>, ic::Ads1013, ic::Resolution12Bit, mode::OneShot>),
+    /// ADS1113 driver
+    Ads1113(Ads1x1x<I2cInterface<I2C>, ic::Ads1113, ic::Resolution16Bit, mode::OneShot>),
+    /// ADS1014 driver
+    Ads1014(Ads1x1x<I2cInterface<I2C>, ic::Ads1014, ic::Resolution12Bit, mode::OneShot>),
+    /// ADS1114 driver
+    Ads1114(Ads1x1x<I2cInterface<I2C>, ic::Ads1114, ic::Resolution16Bit, mode::OneShot>),
+    /// ADS1015 driver
+    Ads1015(Ads1x1x<I2cInterface<I2C>, ic::Ads1015, ic::Resolution12Bit, mode::OneShot>),
+    /// ADS1115 driver
+    Ads1115(Ads1x1x<I2cInterface<I2C>, ic::Ads1115, ic::Resolution16Bit, mode::OneShot>),
+}
+
+macro_rules! with_dev {
+    ($self:expr, $dev:ident => $body:expr) => {
+        match $self {
+            DynamicAds1x1x::Ads1013($dev) => $body,
+            DynamicAds1x1x::Ads1113($dev) => $body,
+            DynamicAds1x1x::Ads1014($dev) => $body,
+            DynamicAds1x1x::Ads1114($dev) => $body,
+            DynamicAds1x1x::Ads1015($dev) => $body,
+            DynamicAds1x1x::Ads1115($dev) => $body,
+        }
+    };
+}
+
+fn to_volts<DI, IC, CONV, MODE, ALERT>(dev: &Ads1x1x<DI, IC, CONV, MODE, ALERT>, value: i16) -> f32
+where
+    CONV: ic::Resolution,
+{
+    conversion::convert_to_volts::<CONV>(value, dev.fsr)
+}
+
+fn to_millivolts<DI, IC, CONV, MODE, ALERT>(
+    dev: &Ads1x1x<DI, IC, CONV, MODE, ALERT>,
+    value: i16,
+) -> i32
+where
+    CONV: ic::Resolution,
+{
+    conversion::convert_to_millivolts::<CONV>(value, dev.fsr)
+}
+
+impl<I2C> DynamicAds1x1x<I2C> {
+    /// Create a new instance of the given device variant in OneShot mode.
+    pub fn new(i2c: I2C, address: SlaveAddr, variant: Variant) -> Self {
+        let iface = I2cInterface {
+            i2c,
+            address: address.addr(DEVICE_BASE_ADDRESS),
+        };
+        match variant {
+            Variant::Ads1013 => DynamicAds1x1x::Ads1013(Ads1x1x::create(iface)),
+            Variant::Ads1113 => DynamicAds1x1x::Ads1113(Ads1x1x::create(iface)),
+            Variant::Ads1014 => DynamicAds1x1x::Ads1014(Ads1x1x::create(iface)),
+            Variant::Ads1114 => DynamicAds1x1x::Ads1114(Ads1x1x::create(iface)),
+            Variant::Ads1015 => DynamicAds1x1x::Ads1015(Ads1x1x::create(iface)),
+            Variant::Ads1115 => DynamicAds1x1x::Ads1115(Ads1x1x::create(iface)),
+        }
+    }
+
+    /// Destroy driver instance, return I²C bus instance.
+    pub fn destroy(self) -> I2C {
+        with_dev!(self, dev => dev.iface.i2c)
+    }
+
+    /// Get the device variant
+    pub fn variant(&self) -> Variant {
+        match self {
+            DynamicAds1x1x::Ads1013(_) => Variant::Ads1013,
+            DynamicAds1x1x::Ads1113(_) => Variant::Ads1113,
+            DynamicAds1x1x::Ads1014(_) => Variant::Ads1014,
+            DynamicAds1x1x::Ads1114(_) => Variant::Ads1114,
+            DynamicAds1x1x::Ads1015(_) => Variant::Ads1015,
+            DynamicAds1x1x::Ads1115(_) => Variant::Ads1115,
+        }
+    }
+
+    /// Get the currently configured full-scale range
+    pub fn full_scale_range(&self) -> FullScaleRange {
+        with_dev!(self, dev => dev.fsr)
+    }
+}
+
+impl<I2C, E> DynamicAds1x1x<I2C>
+where
+    I2cInterface<I2C>: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
+{
+    /// Set the input voltage measurable range
+    ///
+    /// Returns `Error::InvalidInputData` for the ADS1x13, which do not have
+    /// a programmable gain amplifier, unless the range is ±2.048V.
+    pub fn set_full_scale_range(&mut self, range: FullScaleRange) -> Result<(), Error<E>> {
+        match self {
+            DynamicAds1x1x::Ads1013(_) | DynamicAds1x1x::Ads1113(_) => {
+                if range == FullScaleRange::Within2_048V {
+                    Ok(())
+                } else {
+                    Err(Error::InvalidInputData)
+                }
+            }
+            DynamicAds1x1x::Ads1014(dev) => dev.set_full_scale_range(range),
+            DynamicAds1x1x::Ads1114(dev) => dev.set_full_scale_range(range),
+            DynamicAds1x1x::Ads1015(dev) => dev.set_full_scale_range(range),
+            DynamicAds1x1x::Ads1115(dev) => dev.set_full_scale_range(range),
+        }
+    }
+
+    /// Request that the ADC begin a conversion on the specified channel and
+    /// return the result in volts.
+    ///
+    /// See [`read_voltage()`](struct.Ads1x1x.html#method.read_voltage).
+    pub fn read_voltage(&mut self, channel: ChannelSelection) -> nb::Result<f32, Error<E>> {
+        let value = DynamicOneShot::read(self, channel)?;
+        Ok(with_dev!(self, dev => to_volts(dev, value)))
+    }
+
+    /// Request that the ADC begin a conversion on the specified channel and
+    /// return the result in millivolts.
+    ///
+    /// See [`read_millivolts()`](struct.Ads1x1x.html#method.read_millivolts).
+    pub fn read_millivolts(&mut self, channel: ChannelSelection) -> nb::Result<i32, Error<E>> {
+        let value = DynamicOneShot::read(self, channel)?;
+        Ok(with_dev!(self, dev => to_millivolts(dev, value)))
+    }
+}
+
+impl<I2C, E> DynamicOneShot for DynamicAds1x1x<I2C>
+where
+    I2cInterface<I2C>: interface::ReadData<Error = E> + interface::WriteData<Error = E>,
+{
+    type Error = Error<E>;
+
+    /// Request that the ADC begin a conversion on the specified channel.
+    ///
+    /// Returns `Error::InvalidInputData` if the channel is not available
+    /// in the device variant.
+    fn read(&mut self, channel: ChannelSelection) -> nb::Result<i16, Self::Error> {
+        if !self.variant().has_input_multiplexer() && channel != ChannelSelection::DifferentialA0A1
+        {
+            return Err(nb::Error::Other(Error::InvalidInputData));
+        }
+        with_dev!(self, dev => DynamicOneShot::read(dev, channel))
+    }
+}
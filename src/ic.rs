@@ -1,9 +1,86 @@
 /// ICs
-use crate::private;
+use crate::{private, DataRate12Bit, DataRate16Bit};
 
 pub struct Resolution12Bit(pub(crate) ());
 pub struct Resolution16Bit(pub(crate) ());
 
+pub trait Resolution: private::Sealed {
+    /// Data rate type
+    type DataRate: Copy + core::fmt::Debug + PartialEq;
+    /// Decode the data rate from the value of the DR bits.
+    fn data_rate(dr_bits: u8) -> Self::DataRate;
+    /// Encode the data rate into the value of the DR bits.
+    fn data_rate_bits(rate: Self::DataRate) -> u8;
+    /// Nominal data rate in samples per second for each value of the DR bits.
+    const SAMPLE_RATES: [u16; 8];
+    /// Magnitude of the output code corresponding to the full-scale voltage.
+    const FULL_SCALE_CODE: i32;
+}
+
+impl Resolution for Resolution12Bit {
+    type DataRate = DataRate12Bit;
+
+    fn data_rate(dr_bits: u8) -> DataRate12Bit {
+        match dr_bits & 0b111 {
+            0 => DataRate12Bit::Sps128,
+            1 => DataRate12Bit::Sps250,
+            2 => DataRate12Bit::Sps490,
+            3 => DataRate12Bit::Sps920,
+            4 => DataRate12Bit::Sps1600,
+            5 => DataRate12Bit::Sps2400,
+            _ => DataRate12Bit::Sps3300,
+        }
+    }
+
+    fn data_rate_bits(rate: DataRate12Bit) -> u8 {
+        match rate {
+            DataRate12Bit::Sps128 => 0,
+            DataRate12Bit::Sps250 => 1,
+            DataRate12Bit::Sps490 => 2,
+            DataRate12Bit::Sps920 => 3,
+            DataRate12Bit::Sps1600 => 4,
+            DataRate12Bit::Sps2400 => 5,
+            DataRate12Bit::Sps3300 => 6,
+        }
+    }
+
+    const SAMPLE_RATES: [u16; 8] = [128, 250, 490, 920, 1600, 2400, 3300, 3300];
+    const FULL_SCALE_CODE: i32 = 2048;
+}
+
+impl Resolution for Resolution16Bit {
+    type DataRate = DataRate16Bit;
+
+    fn data_rate(dr_bits: u8) -> DataRate16Bit {
+        match dr_bits & 0b111 {
+            0 => DataRate16Bit::Sps8,
+            1 => DataRate16Bit::Sps16,
+            2 => DataRate16Bit::Sps32,
+            3 => DataRate16Bit::Sps64,
+            4 => DataRate16Bit::Sps128,
+            5 => DataRate16Bit::Sps250,
+            6 => DataRate16Bit::Sps475,
+            _ => DataRate16Bit::Sps860,
+        }
+    }
+
+    fn data_rate_bits(rate: DataRate16Bit) -> u8 {
+        match rate {
+            DataRate16Bit::Sps8 => 0,
+            DataRate16Bit::Sps16 => 1,
+            DataRate16Bit::Sps32 => 2,
+            DataRate16Bit::Sps64 => 3,
+            DataRate16Bit::Sps128 => 4,
+            DataRate16Bit::Sps250 => 5,
+            DataRate16Bit::Sps475 => 6,
+            DataRate16Bit::Sps860 => 7,
+        }
+    }
+
+    const SAMPLE_RATES: [u16; 8] = [8, 16, 32, 64, 128, 250, 475, 860];
+    const FULL_SCALE_CODE: i32 = 32768;
+}
+
 macro_rules! ic_marker {
     ($name:ident) => {
         /// IC marker
@@ -30,3 +107,24 @@ tier2_features!(Ads1014);
 tier2_features!(Ads1114);
 tier2_features!(Ads1015);
 tier2_features!(Ads1115);
+
+/// Features of a device as constants
+pub trait Features: private::Sealed {
+    /// Whether the device has a programmable gain amplifier and comparator
+    const TIER2: bool;
+}
+
+macro_rules! features {
+    ($name:ident, $tier2:expr) => {
+        impl Features for $name {
+            const TIER2: bool = $tier2;
+        }
+    };
+}
+
+features!(Ads1013, false);
+features!(Ads1113, false);
+features!(Ads1014, true);
+features!(Ads1114, true);
+features!(Ads1015, true);
+features!(Ads1115, true);
@@ -1,7 +1,14 @@
 //! I2C interface
 
-use crate::{private, Error};
-use embedded_hal::blocking;
+use crate::{
+    private, DeviceRegister, Error, Operation, SlaveAddr, GENERAL_CALL_ADDRESS, GENERAL_CALL_RESET,
+};
+#[cfg(feature = "eh02")]
+use embedded_hal::{blocking, blocking::delay::DelayUs, digital::v2 as digital};
+#[cfg(feature = "eh1")]
+use embedded_hal_1::{delay::DelayNs, digital as digital1, i2c};
+#[cfg(feature = "async")]
+use embedded_hal_async::i2c::I2c as AsyncI2c;
 
 /// I2C interface
 #[derive(Debug, Default)]
@@ -10,22 +17,143 @@ pub struct I2cInterface<I2C> {
     pub(crate) address: u8,
 }
 
+/// Peripheral implementing the `embedded-hal` 1.0 traits
+///
+/// Wrap an I2C bus, an input pin or a delay provider implementing the
+/// `embedded-hal` 1.0 traits in this to use it with the driver.
+///
+/// ```no_run
+/// # #[cfg(feature = "eh1")]
+/// # fn example<I2C: embedded_hal_1::i2c::I2c>(i2c: I2C) {
+/// use ads1x1x::{channel, Ads1x1x, Eh1, SlaveAddr};
+/// use embedded_hal::adc::OneShot;
+/// use nb::block;
+///
+/// let mut adc = Ads1x1x::new_ads1115(Eh1(i2c), SlaveAddr::default());
+/// let measurement = block!(adc.read(&mut channel::SingleA0)).unwrap();
+/// let Eh1(i2c) = adc.destroy_ads1115();
+/// # }
+/// ```
+#[cfg(feature = "eh1")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Eh1<T>(pub T);
+
+/// Write to a device on the I2C bus
+pub trait I2cWrite {
+    /// Error type
+    type Error;
+    /// Write the bytes to the device at the address
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// Write to and then read from a device on the I2C bus
+pub trait I2cWriteRead {
+    /// Error type
+    type Error;
+    /// Write the bytes to the device at the address, then fill the buffer
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>;
+}
+
+/// Read from a device on the I2C bus
+pub trait I2cRead {
+    /// Error type
+    type Error;
+    /// Fill the buffer reading from the device at the address
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+#[cfg(feature = "eh02")]
+impl<I2C: blocking::i2c::Write> I2cWrite for I2C {
+    type Error = I2C::Error;
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        blocking::i2c::Write::write(self, address, bytes)
+    }
+}
+
+#[cfg(feature = "eh02")]
+impl<I2C: blocking::i2c::WriteRead> I2cWriteRead for I2C {
+    type Error = I2C::Error;
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        blocking::i2c::WriteRead::write_read(self, address, bytes, buffer)
+    }
+}
+
+#[cfg(feature = "eh02")]
+impl<I2C: blocking::i2c::Read> I2cRead for I2C {
+    type Error = I2C::Error;
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        blocking::i2c::Read::read(self, address, buffer)
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<I2C: i2c::I2c> I2cWrite for Eh1<I2C> {
+    type Error = I2C::Error;
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        i2c::I2c::write(&mut self.0, address, bytes)
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<I2C: i2c::I2c> I2cWriteRead for Eh1<I2C> {
+    type Error = I2C::Error;
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        i2c::I2c::write_read(&mut self.0, address, bytes, buffer)
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<I2C: i2c::I2c> I2cRead for Eh1<I2C> {
+    type Error = I2C::Error;
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        i2c::I2c::read(&mut self.0, address, buffer)
+    }
+}
+
 /// Write data
 pub trait WriteData: private::Sealed {
     /// Error type
     type Error;
     /// Write to an u16 register
     fn write_register(&mut self, register: u8, data: u16) -> Result<(), Error<Self::Error>>;
+    /// Send an I2C general-call Reset command
+    fn write_general_call_reset(&mut self) -> Result<(), Error<Self::Error>>;
 }
 
 impl<I2C, E> WriteData for I2cInterface<I2C>
 where
-    I2C: blocking::i2c::Write<Error = E>,
+    I2C: I2cWrite<Error = E>,
 {
     type Error = E;
     fn write_register(&mut self, register: u8, data: u16) -> Result<(), Error<E>> {
         let payload: [u8; 3] = [register, (data >> 8) as u8, data as u8];
-        self.i2c.write(self.address, &payload).map_err(Error::I2C)
+        self.i2c.write(self.address, &payload).map_err(|e| {
+            Error::I2C(
+                e,
+                Operation::WriteRegister(DeviceRegister::from_address(register)),
+            )
+        })
+    }
+
+    fn write_general_call_reset(&mut self) -> Result<(), Error<E>> {
+        self.i2c
+            .write(GENERAL_CALL_ADDRESS, &[GENERAL_CALL_RESET])
+            .map_err(|e| Error::I2C(e, Operation::GeneralCallReset))
     }
 }
 
@@ -39,14 +167,137 @@ pub trait ReadData: private::Sealed {
 
 impl<I2C, E> ReadData for I2cInterface<I2C>
 where
-    I2C: blocking::i2c::WriteRead<Error = E>,
+    I2C: I2cWriteRead<Error = E>,
 {
     type Error = E;
     fn read_register(&mut self, register: u8) -> Result<u16, Error<E>> {
         let mut data = [0, 0];
         self.i2c
             .write_read(self.address, &[register], &mut data)
-            .map_err(Error::I2C)
+            .map_err(|e| {
+                Error::I2C(
+                    e,
+                    Operation::ReadRegister(DeviceRegister::from_address(register)),
+                )
+            })
+            .and(Ok((u16::from(data[0]) << 8) | u16::from(data[1])))
+    }
+}
+
+/// Read from the SMBus Alert Response Address
+pub trait ReadAlertResponse: private::Sealed {
+    /// Error type
+    type Error;
+    /// Send an SMBus alert response and decode the responding address
+    fn read_alert_response(&mut self) -> Result<Option<SlaveAddr>, Error<Self::Error>>;
+}
+
+impl<I2C, E> ReadAlertResponse for I2cInterface<I2C>
+where
+    I2C: I2cRead<Error = E>,
+{
+    type Error = E;
+    fn read_alert_response(&mut self) -> Result<Option<SlaveAddr>, Error<E>> {
+        crate::bus::read_alert_response(&mut self.i2c)
+    }
+}
+
+/// Read the level of the pin connected to ALERT/RDY
+pub trait ReadyPin {
+    /// Read whether the pin is high
+    fn is_pin_high<E>(&mut self) -> Result<bool, Error<E>>;
+}
+
+#[cfg(feature = "eh02")]
+impl<P> ReadyPin for P
+where
+    P: digital::InputPin,
+{
+    fn is_pin_high<E>(&mut self) -> Result<bool, Error<E>> {
+        self.is_high().map_err(|_| Error::Pin)
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<P> ReadyPin for Eh1<P>
+where
+    P: digital1::InputPin,
+{
+    fn is_pin_high<E>(&mut self) -> Result<bool, Error<E>> {
+        self.0.is_high().map_err(|_| Error::Pin)
+    }
+}
+
+/// Wait for a number of microseconds
+pub trait Delay {
+    /// Wait for at least `us` microseconds
+    fn wait_us(&mut self, us: u32);
+}
+
+#[cfg(feature = "eh02")]
+impl<D> Delay for D
+where
+    D: DelayUs<u32>,
+{
+    fn wait_us(&mut self, us: u32) {
+        self.delay_us(us);
+    }
+}
+
+#[cfg(feature = "eh1")]
+impl<D> Delay for Eh1<D>
+where
+    D: DelayNs,
+{
+    fn wait_us(&mut self, us: u32) {
+        self.0.delay_us(us);
+    }
+}
+
+/// Asynchronous I2C interface
+#[cfg(feature = "async")]
+#[derive(Debug, Default)]
+pub struct AsyncI2cInterface<I2C> {
+    pub(crate) i2c: I2C,
+    pub(crate) address: u8,
+}
+
+#[cfg(feature = "async")]
+impl<I2C, E> AsyncI2cInterface<I2C>
+where
+    I2C: AsyncI2c<Error = E>,
+{
+    /// Write to an u16 register
+    pub(crate) async fn write_register(&mut self, register: u8, data: u16) -> Result<(), Error<E>> {
+        let payload: [u8; 3] = [register, (data >> 8) as u8, data as u8];
+        self.i2c.write(self.address, &payload).await.map_err(|e| {
+            Error::I2C(
+                e,
+                Operation::WriteRegister(DeviceRegister::from_address(register)),
+            )
+        })
+    }
+
+    /// Send an I2C general-call Reset command
+    pub(crate) async fn write_general_call_reset(&mut self) -> Result<(), Error<E>> {
+        self.i2c
+            .write(GENERAL_CALL_ADDRESS, &[GENERAL_CALL_RESET])
+            .await
+            .map_err(|e| Error::I2C(e, Operation::GeneralCallReset))
+    }
+
+    /// Read an u16 register
+    pub(crate) async fn read_register(&mut self, register: u8) -> Result<u16, Error<E>> {
+        let mut data = [0, 0];
+        self.i2c
+            .write_read(self.address, &[register], &mut data)
+            .await
+            .map_err(|e| {
+                Error::I2C(
+                    e,
+                    Operation::ReadRegister(DeviceRegister::from_address(register)),
+                )
+            })
             .and(Ok((u16::from(data[0]) << 8) | u16::from(data[1])))
     }
 }
@@ -9,32 +9,95 @@
 //! - Make a measurement in one-shot mode. See: [`read()`][read_os].
 //! - Start continuous conversion mode. See: [`start()`].
 //! - Read the last measurement made in continuous conversion mode. See: [`read()`][read_cont].
+//! - Make a measurement and convert it into volts or millivolts using the
+//!   configured full-scale range. See: [`read_voltage()`] and [`read_millivolts()`].
 //! - Set the data rate. See: [`set_data_rate()`].
 //! - Set the full-scale range (gain amplifier). See [`set_full_scale_range()`].
+//! - Change several settings with a single configuration write. See: [`configure()`].
 //! - Read whether a measurement is in progress. See: [`is_measurement_in_progress()`].
+//! - Read the device configuration and take it over into the driver.
+//!   See: [`read_configuration()`] and [`sync_from_device()`].
+//! - Get the settings cached in the driver. See: [`configuration()`], [`data_rate()`],
+//!   [`full_scale_range()`] and [`selected_channel()`].
 //! - Set the ALERT/RDY pin to be used as conversion-ready pin. See: [`use_alert_rdy_pin_as_ready()`].
+//! - Make a measurement waiting for the ALERT/RDY pin instead of polling the device.
+//!   See: [`read_with_ready_pin()`].
+//! - Make a blocking measurement sleeping for the conversion time. See: [`read_blocking()`].
+//! - Limit how long to wait for a conversion to finish. See: [`set_conversion_poll_limit()`].
+//! - Reset the devices through an I2C general-call Reset command. See: [`general_call_reset()`].
+//! - Find the devices present on a bus and tell 12-bit from 16-bit devices. See: [`bus::scan()`].
+//! - Detect the device resolution at runtime and drive any variant through a
+//!   single type. See: [`bus::detect_resolution()`] and [`DynamicAds1x1x`].
+//! - Share one I2C bus between several devices. See: [`bus::RefCellBus`].
+//! - Measure several channels back-to-back, each with its own full-scale range.
+//!   See: [`read_sequence()`].
+//! - Measure each channel with its own full-scale range and data rate without
+//!   additional register writes. See: [`set_channel_profile()`].
+//! - Measure several channels in turn in continuous-conversion mode and tell
+//!   which channel each measurement belongs to. See: [`read_scan()`].
+//! - Read each measurement only once in continuous-conversion mode. See: [`read_new()`].
+//! - Stream measurements in continuous-conversion mode into a buffer or ring buffer.
+//!   See: [`stream`].
+//! - Choose the full-scale range automatically for each measurement. See: [`read_auto_range()`].
+//! - Detect measurements clipped at the limits of the full-scale range.
+//!   See: [`read_measurement()`] and [`set_out_of_range_error()`].
 //! - Comparator:
 //!     - Set the low and high thresholds. See: [`set_high_threshold_raw()`].
+//!     - Set the low and high thresholds in volts. See: [`set_threshold_window_voltage()`].
+//!     - Read the low and high thresholds. See: [`low_threshold_raw()`] and [`high_threshold_voltage()`].
 //!     - Set the comparator mode. See: [`set_comparator_mode()`].
 //!     - Set the comparator polarity. See: [`set_comparator_polarity()`].
 //!     - Set the comparator latching. See: [`set_comparator_latching()`].
-//!     - Set the comparator queue. See: [`set_comparator_queue()`].
+//!     - Enable the comparator and set the comparator queue. See: [`enable_comparator()`].
 //!     - Disable the comparator. See: [`disable_comparator()`].
+//!     - Find out which device asserted the ALERT/RDY line and clear its latch.
+//!       See: [`read_alert_response()`] and [`bus::read_alert_response()`].
 //!
 //! [`into_continuous()`]: struct.Ads1x1x.html#method.into_continuous
 //! [read_os]: struct.Ads1x1x.html#method.read
 //! [`start()`]: struct.Ads1x1x.html#method.start
 //! [read_cont]: struct.Ads1x1x.html#impl-OneShot%3CAds1x1x%3CDI%2C%20IC%2C%20CONV%2C%20OneShot%3E%2C%20i16%2C%20CH%3E
+//! [`read_voltage()`]: struct.Ads1x1x.html#method.read_voltage
+//! [`read_millivolts()`]: struct.Ads1x1x.html#method.read_millivolts
 //! [`set_data_rate()`]: struct.Ads1x1x.html#method.set_data_rate
 //! [`set_full_scale_range()`]: struct.Ads1x1x.html#method.set_full_scale_range
+//! [`configure()`]: struct.Ads1x1x.html#method.configure
 //! [`is_measurement_in_progress()`]: struct.Ads1x1x.html#method.is_measurement_in_progress
+//! [`read_configuration()`]: struct.Ads1x1x.html#method.read_configuration
+//! [`sync_from_device()`]: struct.Ads1x1x.html#method.sync_from_device
+//! [`configuration()`]: struct.Ads1x1x.html#method.configuration
+//! [`data_rate()`]: struct.Ads1x1x.html#method.data_rate
+//! [`full_scale_range()`]: struct.Ads1x1x.html#method.full_scale_range
+//! [`selected_channel()`]: struct.Ads1x1x.html#method.selected_channel
 //! [`set_high_threshold_raw()`]: struct.Ads1x1x.html#method.set_high_threshold_raw
+//! [`set_threshold_window_voltage()`]: struct.Ads1x1x.html#method.set_threshold_window_voltage
+//! [`low_threshold_raw()`]: struct.Ads1x1x.html#method.low_threshold_raw
+//! [`high_threshold_voltage()`]: struct.Ads1x1x.html#method.high_threshold_voltage
 //! [`set_comparator_mode()`]: struct.Ads1x1x.html#method.set_comparator_mode
 //! [`set_comparator_polarity()`]: struct.Ads1x1x.html#method.set_comparator_polarity
 //! [`set_comparator_latching()`]: struct.Ads1x1x.html#method.set_comparator_latching
-//! [`set_comparator_queue()`]: struct.Ads1x1x.html#method.set_comparator_queue
+//! [`enable_comparator()`]: struct.Ads1x1x.html#method.enable_comparator
 //! [`disable_comparator()`]: struct.Ads1x1x.html#method.disable_comparator
+//! [`read_alert_response()`]: struct.Ads1x1x.html#method.read_alert_response
+//! [`bus::read_alert_response()`]: bus/fn.read_alert_response.html
+//! [`bus::scan()`]: bus/fn.scan.html
+//! [`bus::detect_resolution()`]: bus/fn.detect_resolution.html
+//! [`DynamicAds1x1x`]: enum.DynamicAds1x1x.html
+//! [`bus::RefCellBus`]: bus/struct.RefCellBus.html
+//! [`read_sequence()`]: struct.Ads1x1x.html#method.read_sequence
+//! [`set_channel_profile()`]: struct.Ads1x1x.html#method.set_channel_profile
+//! [`read_scan()`]: struct.Ads1x1x.html#method.read_scan
+//! [`read_new()`]: struct.Ads1x1x.html#method.read_new
+//! [`stream`]: stream/index.html
+//! [`read_auto_range()`]: struct.Ads1x1x.html#method.read_auto_range
+//! [`read_measurement()`]: struct.Ads1x1x.html#method.read_measurement
+//! [`set_out_of_range_error()`]: struct.Ads1x1x.html#method.set_out_of_range_error
 //! [`use_alert_rdy_pin_as_ready()`]: struct.Ads1x1x.html#method.use_alert_rdy_pin_as_ready
+//! [`read_with_ready_pin()`]: struct.Ads1x1x.html#method.read_with_ready_pin
+//! [`read_blocking()`]: struct.Ads1x1x.html#method.read_blocking
+//! [`set_conversion_poll_limit()`]: struct.Ads1x1x.html#method.set_conversion_poll_limit
+//! [`Eh1`]: struct.Eh1.html
+//! [`general_call_reset()`]: struct.Ads1x1x.html#method.general_call_reset
 //!
 //! ## The devices
 //!
@@ -87,6 +150,8 @@
 //! ### Create a driver instance for the ADS1013
 //!
 //! ```no_run
+//! # #[cfg(feature = "eh02")]
+//! # fn main() {
 //! use linux_embedded_hal::I2cdev;
 //! use ads1x1x::{Ads1x1x, SlaveAddr};
 //!
@@ -97,11 +162,16 @@
 //!
 //! // get the I2C device back
 //! let dev = adc.destroy_ads1013();
+//! # }
+//! # #[cfg(not(feature = "eh02"))]
+//! # fn main() {}
 //! ```
 //!
 //! ### Create a driver instance for the ADS1013 with an alternative address (method 1)
 //!
 //! ```no_run
+//! # #[cfg(feature = "eh02")]
+//! # fn main() {
 //! use linux_embedded_hal::I2cdev;
 //! use ads1x1x::{Ads1x1x, SlaveAddr};
 //!
@@ -109,6 +179,9 @@
 //! let (bit1, bit0) = (true, false); // last two bits of address
 //! let address = SlaveAddr::Alternative(bit1, bit0);
 //! let adc = Ads1x1x::new_ads1013(dev, address);
+//! # }
+//! # #[cfg(not(feature = "eh02"))]
+//! # fn main() {}
 //! ```
 
 //! ### Create a driver instance for the ADS1013 with an alternative address (method 2)
@@ -117,6 +190,8 @@
 //! the `ADDR` pin.
 //!
 //! ```no_run
+//! # #[cfg(feature = "eh02")]
+//! # fn main() {
 //! use linux_embedded_hal::I2cdev;
 //! use ads1x1x::{Ads1x1x, SlaveAddr};
 //!
@@ -124,10 +199,15 @@
 //! // `ADDR` pin connected to SDA results in the 0x4A effective address
 //! let address = SlaveAddr::new_sda();
 //! let adc = Ads1x1x::new_ads1013(dev, address);
+//! # }
+//! # #[cfg(not(feature = "eh02"))]
+//! # fn main() {}
 //! ```
 //!
 //! ### Make a one-shot measurement
 //! ```no_run
+//! # #[cfg(feature = "eh02")]
+//! # fn main() {
 //! use ads1x1x::{channel, Ads1x1x, SlaveAddr};
 //! use embedded_hal::adc::OneShot;
 //! use linux_embedded_hal::I2cdev;
@@ -138,6 +218,31 @@
 //! let measurement = block!(adc.read(&mut channel::DifferentialA0A1)).unwrap();
 //! println!("Measurement: {}", measurement);
 //! let _dev = adc.destroy_ads1013(); // get I2C device back
+//! # }
+//! # #[cfg(not(feature = "eh02"))]
+//! # fn main() {}
+//! ```
+//!
+//! ### Make a one-shot measurement in volts
+//!
+//! The measurement is scaled using the configured full-scale range.
+//! On the ADS1x13 the full-scale range is fixed at ±2.048 V.
+//!
+//! ```no_run
+//! # #[cfg(feature = "eh02")]
+//! # fn main() {
+//! use ads1x1x::{channel, Ads1x1x, FullScaleRange, SlaveAddr};
+//! use linux_embedded_hal::I2cdev;
+//! use nb::block;
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let mut adc = Ads1x1x::new_ads1115(dev, SlaveAddr::default());
+//! adc.set_full_scale_range(FullScaleRange::Within4_096V).unwrap();
+//! let voltage = block!(adc.read_voltage(&mut channel::SingleA0)).unwrap();
+//! println!("Voltage: {} V", voltage);
+//! # }
+//! # #[cfg(not(feature = "eh02"))]
+//! # fn main() {}
 //! ```
 //!
 //! ### Change into continuous conversion mode and read the last measurement
@@ -146,6 +251,8 @@
 //! In this case, you can retrieve the unchanged device from the error type.
 //!
 //! ```no_run
+//! # #[cfg(feature = "eh02")]
+//! # fn main() {
 //! use linux_embedded_hal::I2cdev;
 //! use ads1x1x::{Ads1x1x, ModeChangeError, SlaveAddr};
 //!
@@ -159,6 +266,9 @@
 //!         // ...
 //!     }
 //! }
+//! # }
+//! # #[cfg(not(feature = "eh02"))]
+//! # fn main() {}
 //! ```
 //!
 //!
@@ -167,6 +277,8 @@
 //! For 16-bit devices, the available data rates are given by `DataRate16Bit`.
 //!
 //! ```no_run
+//! # #[cfg(feature = "eh02")]
+//! # fn main() {
 //! use linux_embedded_hal::I2cdev;
 //! use ads1x1x::{Ads1x1x, DataRate16Bit, SlaveAddr};
 //!
@@ -174,6 +286,9 @@
 //! let address = SlaveAddr::default();
 //! let mut adc = Ads1x1x::new_ads1115(dev, address);
 //! adc.set_data_rate(DataRate16Bit::Sps860).unwrap();
+//! # }
+//! # #[cfg(not(feature = "eh02"))]
+//! # fn main() {}
 //! ```
 //!
 //! ### Configure the comparator
@@ -183,7 +298,13 @@
 //! measurement is read or an appropriate SMBus alert response is sent by
 //! the master.
 //!
+//! The role of the ALERT/RDY pin is encoded in the type of the driver.
+//! The comparator is enabled last so that it does not assert while the
+//! thresholds are being configured.
+//!
 //! ```no_run
+//! # #[cfg(feature = "eh02")]
+//! # fn main() {
 //! use linux_embedded_hal::I2cdev;
 //! use ads1x1x::{
 //!     Ads1x1x, SlaveAddr, ComparatorQueue, ComparatorPolarity,
@@ -193,21 +314,108 @@
 //! let dev = I2cdev::new("/dev/i2c-1").unwrap();
 //! let address = SlaveAddr::default();
 //! let mut adc = Ads1x1x::new_ads1015(dev, address);
-//! adc.set_comparator_queue(ComparatorQueue::Two).unwrap();
 //! adc.set_comparator_polarity(ComparatorPolarity::ActiveHigh).unwrap();
 //! adc.set_comparator_mode(ComparatorMode::Window).unwrap();
 //! adc.set_full_scale_range(FullScaleRange::Within2_048V).unwrap();
-//! adc.set_low_threshold_raw(-1500).unwrap();
-//! adc.set_high_threshold_raw(1500).unwrap();
+//! adc.set_threshold_window_voltage(-1.5, 1.5).unwrap();
 //! adc.set_comparator_latching(ComparatorLatching::Latching).unwrap();
+//! let adc = adc.enable_comparator(ComparatorQueue::Two).ok().unwrap();
+//! # }
+//! # #[cfg(not(feature = "eh02"))]
+//! # fn main() {}
+//! ```
+//!
+//! ### Use the ALERT/RDY pin as conversion-ready pin
+//!
+//! Once in this role, the comparator thresholds can no longer be changed.
+//!
+//! ```no_run
+//! # #[cfg(feature = "eh02")]
+//! # fn main() {
+//! use linux_embedded_hal::I2cdev;
+//! use ads1x1x::{Ads1x1x, SlaveAddr};
+//!
+//! let dev = I2cdev::new("/dev/i2c-1").unwrap();
+//! let adc = Ads1x1x::new_ads1115(dev, SlaveAddr::default());
+//! let adc = adc.use_alert_rdy_pin_as_ready().ok().unwrap();
+//! // ...
+//! let adc = adc.disable_comparator().ok().unwrap();
+//! # }
+//! # #[cfg(not(feature = "eh02"))]
+//! # fn main() {}
 //! ```
+//!
+//! ## Features
+//!
+//! ### Selecting the `embedded-hal` version
+//!
+//! By default, the I2C bus must implement the `embedded-hal` 0.2 traits
+//! `blocking::i2c::Write` and `blocking::i2c::WriteRead`. With the `eh1`
+//! feature enabled, an I2C bus implementing the `embedded-hal` 1.0
+//! `i2c::I2c` trait can be used by wrapping it in [`Eh1`]. The same goes
+//! for the input pin and delay provider arguments of some methods.
+//!
+//! ```toml
+//! [dependencies]
+//! ads1x1x = { version = "0.2", features = ["eh1"] }
+//! ```
+//!
+//! The `eh02` and `eh1` features can be enabled at the same time.
+//! The `eh02` feature is enabled by default.
+//! The ADC traits (`adc::OneShot`, `adc::Channel`) were removed in
+//! `embedded-hal` 1.0 so these are always taken from `embedded-hal` 0.2.
+//!
+//! ### Errors
+//!
+//! I2C errors are returned together with the [`Operation`] during which
+//! they happened. All errors implement `core::fmt::Display` and, with the
+//! `std` feature enabled, `std::error::Error`.
+//! With the `eh1` feature the driver error implements the `embedded-hal` 1.0
+//! `i2c::Error` trait and forwards the error kind of the I2C bus error.
+//!
+//! ### Asynchronous operation
+//!
+//! Enabling the `async` feature makes it possible to create driver instances
+//! on top of an `embedded-hal-async` I2C bus with methods like
+//! `Ads1x1x::new_ads1115_async(...)`. All methods of these instances are
+//! `async` and have the same names as their blocking counterparts.
+//! The `async` feature requires Rust 1.75 or later.
+//! Measurements in one-shot mode are made with `read(channel).await`, which
+//! polls the device until the conversion has finished, or with
+//! `read_with_delay(channel, delay).await`, which waits for the conversion
+//! time corresponding to the configured data rate using an
+//! `embedded-hal-async` delay before reading the result.
+//!
+//! ```no_run
+//! # #[cfg(feature = "async")]
+//! # async fn example<I2C: embedded_hal_async::i2c::I2c>(i2c: I2C) {
+//! use ads1x1x::{channel, Ads1x1x, DataRate16Bit, SlaveAddr};
+//!
+//! let mut adc = Ads1x1x::new_ads1115_async(i2c, SlaveAddr::default());
+//! adc.set_data_rate(DataRate16Bit::Sps860).await.unwrap();
+//! let measurement = adc.read(&mut channel::SingleA0).await.unwrap();
+//! # }
+//! ```
+//!
+//! ### Streaming
+//!
+//! The `heapless` feature implements [`stream::SampleSink`] for `heapless`
+//! containers so that measurements can be streamed into them.
+//!
+//! [`stream::SampleSink`]: stream/trait.SampleSink.html
 
 #![doc(html_root_url = "https://docs.rs/ads1x1x/0.2.2")]
 #![deny(unsafe_code)]
 #![deny(missing_docs)]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 const DEVICE_BASE_ADDRESS: u8 = 0b100_1000;
+const GENERAL_CALL_ADDRESS: u8 = 0x00;
+const GENERAL_CALL_RESET: u8 = 0x06;
+const ALERT_RESPONSE_ADDRESS: u8 = 0x0C;
 
 struct Register;
 impl Register {
@@ -217,6 +425,10 @@ impl Register {
     const HIGH_TH: u8 = 0x03;
 }
 
+/// Default number of times the device is polled after waiting for the conversion time
+/// before giving up with `Error::Timeout`.
+const CONVERSION_POLL_RETRIES: u32 = 16;
+
 struct BitFlags;
 impl BitFlags {
     const OS: u16 = 0b1000_0000_0000_0000;
@@ -237,30 +449,39 @@ impl BitFlags {
     const COMP_QUE0: u16 = 0b0000_0000_0000_0001;
 }
 
+pub mod bus;
 mod channels;
 pub use crate::channels::{channel, ChannelSelection};
 mod construction;
 mod conversion;
 pub use crate::conversion::{ConvertMeasurement, ConvertThreshold};
 mod devices;
+mod dynamic;
+pub use crate::dynamic::{DynamicAds1x1x, Variant};
 #[doc(hidden)]
 pub mod ic;
 #[doc(hidden)]
 pub mod interface;
+#[cfg(feature = "eh1")]
+pub use crate::interface::Eh1;
+pub mod stream;
 mod types;
 use crate::types::Config;
 pub use crate::types::{
-    mode, Ads1x1x, ComparatorLatching, ComparatorMode, ComparatorPolarity, ComparatorQueue,
-    DataRate12Bit, DataRate16Bit, DynamicOneShot, Error, FullScaleRange, ModeChangeError,
+    alert, mode, Ads1x1x, AutoRange, AutoRangeMeasurement, ChannelProfile, ChannelScan, Clipping,
+    ComparatorLatching, ComparatorMode, ComparatorPolarity, ComparatorQueue, ConfigBuilder,
+    Configuration, DataRate12Bit, DataRate16Bit, DeviceRegister, DynamicOneShot, Error,
+    FullScaleRange, Measurement, ModeChangeError, OperatingMode, Operation, Sequence, SequenceStep,
     SlaveAddr,
 };
 
 mod private {
-    use super::{ic, interface, Ads1x1x};
+    use super::{alert, ic, interface, Ads1x1x, DynamicAds1x1x};
     pub trait Sealed {}
 
     impl<I2C> Sealed for interface::I2cInterface<I2C> {}
-    impl<DI, IC, CONV, MODE> Sealed for Ads1x1x<DI, IC, CONV, MODE> {}
+    impl<DI, IC, CONV, MODE, ALERT> Sealed for Ads1x1x<DI, IC, CONV, MODE, ALERT> {}
+    impl<I2C> Sealed for DynamicAds1x1x<I2C> {}
 
     impl Sealed for ic::Resolution12Bit {}
     impl Sealed for ic::Resolution16Bit {}
@@ -271,4 +492,8 @@ mod private {
     impl Sealed for ic::Ads1114 {}
     impl Sealed for ic::Ads1015 {}
     impl Sealed for ic::Ads1115 {}
+
+    impl Sealed for alert::Disabled {}
+    impl Sealed for alert::Comparator {}
+    impl Sealed for alert::ConversionReady {}
 }
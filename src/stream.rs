@@ -0,0 +1,159 @@
+//! Streaming of continuous-conversion mode measurements into memory.
+//!
+//! A [`Stream`](struct.Stream.html) stores the measurements in a
+//! [`SampleSink`](trait.SampleSink.html), for example a caller-provided
+//! buffer through [`SliceSink`](struct.SliceSink.html) or, with the
+//! `heapless` feature, a `heapless::Deque` ring buffer or the producer side
+//! of a `heapless::spsc::Queue`. Nothing is allocated.
+//!
+//! The stream is driven either by the conversion-ready pulse of the
+//! ALERT/RDY pin through
+//! [`stream_ready()`](../struct.Ads1x1x.html#method.stream_ready), or by
+//! calling [`stream_poll()`](../struct.Ads1x1x.html#method.stream_poll)
+//! periodically.
+
+/// Measurement taken while streaming
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    /// Raw measurement
+    pub value: i16,
+    /// Time of the measurement in microseconds, if known
+    pub timestamp_us: Option<u32>,
+}
+
+/// Storage for streamed samples
+pub trait SampleSink {
+    /// Store a sample.
+    ///
+    /// Returns the sample back if there is no space left for it.
+    fn push(&mut self, sample: Sample) -> Result<(), Sample>;
+}
+
+/// Sample sink filling a caller-provided buffer
+#[derive(Debug)]
+pub struct SliceSink<'a> {
+    buffer: &'a mut [Sample],
+    len: usize,
+}
+
+impl<'a> SliceSink<'a> {
+    /// Create a sink filling the given buffer from its start
+    pub fn new(buffer: &'a mut [Sample]) -> Self {
+        SliceSink { buffer, len: 0 }
+    }
+
+    /// Get the samples stored so far
+    pub fn samples(&self) -> &[Sample] {
+        &self.buffer[..self.len]
+    }
+
+    /// Get the number of samples stored so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no samples were stored so far
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether the buffer is full
+    pub fn is_full(&self) -> bool {
+        self.len == self.buffer.len()
+    }
+
+    /// Discard the stored samples so that the buffer is filled from its
+    /// start again.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl SampleSink for SliceSink<'_> {
+    fn push(&mut self, sample: Sample) -> Result<(), Sample> {
+        match self.buffer.get_mut(self.len) {
+            Some(slot) => {
+                *slot = sample;
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(sample),
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> SampleSink for heapless::Vec<Sample, N> {
+    fn push(&mut self, sample: Sample) -> Result<(), Sample> {
+        heapless::Vec::push(self, sample)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> SampleSink for heapless::Deque<Sample, N> {
+    fn push(&mut self, sample: Sample) -> Result<(), Sample> {
+        self.push_back(sample)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> SampleSink for heapless::spsc::Producer<'_, Sample, N> {
+    fn push(&mut self, sample: Sample) -> Result<(), Sample> {
+        self.enqueue(sample)
+    }
+}
+
+/// Streaming state of continuous-conversion mode measurements
+///
+/// This holds the sample sink and counts the lost samples.
+#[derive(Debug)]
+pub struct Stream<S> {
+    pub(crate) sink: S,
+    pub(crate) overruns: u32,
+}
+
+impl<S: SampleSink> Stream<S> {
+    /// Create a stream storing the samples in the given sink
+    pub fn new(sink: S) -> Self {
+        Stream { sink, overruns: 0 }
+    }
+
+    /// Get the sample sink
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Get the sample sink mutably, for example to take samples out of it
+    pub fn sink_mut(&mut self) -> &mut S {
+        &mut self.sink
+    }
+
+    /// Destroy the stream, return the sample sink
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+
+    /// Get the number of samples lost since the stream was created or the
+    /// count was reset.
+    ///
+    /// Samples are lost when the sink is full and, when polling, when
+    /// conversions finished without being read.
+    pub fn overruns(&self) -> u32 {
+        self.overruns
+    }
+
+    /// Reset the number of lost samples
+    pub fn reset_overruns(&mut self) {
+        self.overruns = 0;
+    }
+
+    pub(crate) fn push(&mut self, sample: Sample) {
+        if self.sink.push(sample).is_err() {
+            self.add_overruns(1);
+        }
+    }
+
+    pub(crate) fn add_overruns(&mut self, count: u32) {
+        self.overruns = self.overruns.saturating_add(count);
+    }
+}
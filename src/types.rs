@@ -1,15 +1,36 @@
 //! Type definitions.
 
-use crate::{channels::ChannelSelection, private};
-use core::marker::PhantomData;
+use crate::{channels::ChannelSelection, ic, private};
+use core::{fmt, marker::PhantomData};
 
 /// Errors in this crate
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Error<E> {
-    /// I²C bus error
-    I2C(E),
+    /// I²C bus error during the given operation
+    I2C(E, Operation),
     /// Invalid input data provided
     InvalidInputData,
+    /// Error reading the pin connected to ALERT/RDY
+    Pin,
+    /// The conversion did not finish in the expected time
+    Timeout,
+    /// The comparator of the device is enabled or disabled contrary to the
+    /// ALERT/RDY pin role of the driver
+    ///
+    /// Returned by [`sync_from_device()`](struct.Ads1x1x.html#method.sync_from_device).
+    ComparatorMismatch,
+    /// The device configuration changed while a conversion was in progress
+    ///
+    /// This happens if the device was reset, for example through a
+    /// general-call reset or a brown-out. The cached configuration has been
+    /// written to the device again and a new conversion triggered, but the
+    /// thresholds may need to be set again.
+    ConfigurationChanged,
+    /// The measurement was clipped at the limit of the full-scale range
+    ///
+    /// Only returned if enabled with
+    /// [`set_out_of_range_error()`](struct.Ads1x1x.html#method.set_out_of_range_error).
+    OutOfRange,
 }
 
 /// Error type for mode changes.
@@ -23,6 +44,123 @@ pub enum ModeChangeError<E, DEV> {
     I2C(E, DEV),
 }
 
+/// Device register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceRegister {
+    /// Conversion register
+    Conversion,
+    /// Configuration register
+    Config,
+    /// Low threshold register
+    LowThreshold,
+    /// High threshold register
+    HighThreshold,
+}
+
+impl DeviceRegister {
+    pub(crate) fn from_address(address: u8) -> Self {
+        match address & 0b11 {
+            0 => DeviceRegister::Conversion,
+            1 => DeviceRegister::Config,
+            2 => DeviceRegister::LowThreshold,
+            _ => DeviceRegister::HighThreshold,
+        }
+    }
+}
+
+/// I²C operation during which an error happened
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Operation {
+    /// Reading a register
+    ReadRegister(DeviceRegister),
+    /// Writing a register
+    WriteRegister(DeviceRegister),
+    /// Sending an I2C general-call Reset command
+    GeneralCallReset,
+    /// Reading from the SMBus Alert Response Address
+    AlertResponse,
+}
+
+impl fmt::Display for DeviceRegister {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceRegister::Conversion => f.write_str("conversion register"),
+            DeviceRegister::Config => f.write_str("configuration register"),
+            DeviceRegister::LowThreshold => f.write_str("low threshold register"),
+            DeviceRegister::HighThreshold => f.write_str("high threshold register"),
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::ReadRegister(register) => write!(f, "reading the {}", register),
+            Operation::WriteRegister(register) => write!(f, "writing the {}", register),
+            Operation::GeneralCallReset => f.write_str("sending a general-call reset"),
+            Operation::AlertResponse => f.write_str("reading the alert response address"),
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::I2C(e, operation) => write!(f, "I2C error while {}: {:?}", operation, e),
+            Error::InvalidInputData => f.write_str("invalid input data"),
+            Error::Pin => f.write_str("error reading the ALERT/RDY pin"),
+            Error::Timeout => f.write_str("conversion did not finish in time"),
+            Error::ComparatorMismatch => {
+                f.write_str("device comparator state does not match the ALERT/RDY pin role")
+            }
+            Error::ConfigurationChanged => f.write_str("device configuration changed"),
+            Error::OutOfRange => f.write_str("measurement out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug> std::error::Error for Error<E> {}
+
+#[cfg(feature = "eh1")]
+impl<E> embedded_hal_1::i2c::Error for Error<E>
+where
+    E: embedded_hal_1::i2c::Error,
+{
+    fn kind(&self) -> embedded_hal_1::i2c::ErrorKind {
+        match self {
+            Error::I2C(e, _) => e.kind(),
+            _ => embedded_hal_1::i2c::ErrorKind::Other,
+        }
+    }
+}
+
+impl<E: fmt::Debug, DEV> fmt::Debug for ModeChangeError<E, DEV> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModeChangeError::I2C(e, _) => f
+                .debug_tuple("I2C")
+                .field(e)
+                .field(&format_args!(".."))
+                .finish(),
+        }
+    }
+}
+
+impl<E: fmt::Debug, DEV> fmt::Display for ModeChangeError<E, DEV> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModeChangeError::I2C(e, _) => {
+                write!(f, "I2C error, the driver state is unchanged: {:?}", e)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug, DEV> std::error::Error for ModeChangeError<E, DEV> {}
+
 /// Mode marker types
 pub mod mode {
     /// One-shot operating mode / power-down state (default)
@@ -32,6 +170,74 @@ pub mod mode {
     pub struct Continuous(());
 }
 
+/// ALERT/RDY pin role marker types
+///
+/// These are only relevant for devices featuring a comparator (ADS1x14, ADS1x15).
+///
+/// The comparator thresholds cannot be changed while the ALERT/RDY pin is
+/// used as conversion-ready pin:
+///
+/// ```compile_fail
+/// use ads1x1x::{interface, Ads1x1x, SlaveAddr};
+///
+/// fn set_threshold<I2C, E>(i2c: I2C)
+/// where
+///     I2C: interface::I2cWrite<Error = E> + interface::I2cWriteRead<Error = E>,
+/// {
+///     let adc = Ads1x1x::new_ads1115(i2c, SlaveAddr::default());
+///     let mut adc = adc.use_alert_rdy_pin_as_ready().ok().unwrap();
+///     adc.set_low_threshold_raw(0).ok();
+/// }
+/// ```
+pub mod alert {
+    use crate::private;
+
+    /// The comparator is disabled and the ALERT/RDY pin is in high-impedance state (default)
+    pub struct Disabled(());
+
+    /// The ALERT/RDY pin is driven by the comparator
+    pub struct Comparator(());
+
+    /// The ALERT/RDY pin is used as conversion-ready pin
+    pub struct ConversionReady(());
+
+    /// Roles in which the comparator thresholds can be changed
+    #[doc(hidden)]
+    pub trait ConfigurableThresholds: private::Sealed {}
+
+    impl ConfigurableThresholds for Disabled {}
+    impl ConfigurableThresholds for Comparator {}
+
+    /// Comparator state of the roles
+    #[doc(hidden)]
+    pub trait Role: private::Sealed {
+        /// Whether the comparator is enabled in this role
+        const COMPARATOR_ENABLED: bool;
+    }
+
+    impl Role for Disabled {
+        const COMPARATOR_ENABLED: bool = false;
+    }
+    impl Role for Comparator {
+        const COMPARATOR_ENABLED: bool = true;
+    }
+    impl Role for ConversionReady {
+        const COMPARATOR_ENABLED: bool = true;
+    }
+}
+
+/// Operating mode
+///
+/// The operating mode of a driver instance is encoded in its type.
+/// See [`mode`](mode/index.html).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OperatingMode {
+    /// One-shot operating mode / power-down state (default)
+    OneShot,
+    /// Continuous conversion mode
+    Continuous,
+}
+
 /// Data rate for ADS1013, ADS1014, ADS1015
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DataRate12Bit {
@@ -116,6 +322,7 @@ pub enum ComparatorLatching {
     /// read by the master or an appropriate SMBus alert response is sent by
     /// the master. The device responds with its address, and it is the lowest
     /// address currently asserting the ALERT/RDY bus line.
+    /// See [`read_alert_response()`](struct.Ads1x1x.html#method.read_alert_response).
     Latching,
 }
 
@@ -154,6 +361,124 @@ pub enum FullScaleRange {
     Within0_256V,
 }
 
+impl FullScaleRange {
+    /// Full-scale voltage in millivolts
+    pub(crate) fn millivolts(self) -> i32 {
+        match self {
+            FullScaleRange::Within6_144V => 6144,
+            FullScaleRange::Within4_096V => 4096,
+            FullScaleRange::Within2_048V => 2048,
+            FullScaleRange::Within1_024V => 1024,
+            FullScaleRange::Within0_512V => 512,
+            FullScaleRange::Within0_256V => 256,
+        }
+    }
+
+    /// Next wider range, if any
+    pub(crate) fn wider(self) -> Option<Self> {
+        match self {
+            FullScaleRange::Within6_144V => None,
+            FullScaleRange::Within4_096V => Some(FullScaleRange::Within6_144V),
+            FullScaleRange::Within2_048V => Some(FullScaleRange::Within4_096V),
+            FullScaleRange::Within1_024V => Some(FullScaleRange::Within2_048V),
+            FullScaleRange::Within0_512V => Some(FullScaleRange::Within1_024V),
+            FullScaleRange::Within0_256V => Some(FullScaleRange::Within0_512V),
+        }
+    }
+
+    /// Next narrower range, if any
+    pub(crate) fn narrower(self) -> Option<Self> {
+        match self {
+            FullScaleRange::Within6_144V => Some(FullScaleRange::Within4_096V),
+            FullScaleRange::Within4_096V => Some(FullScaleRange::Within2_048V),
+            FullScaleRange::Within2_048V => Some(FullScaleRange::Within1_024V),
+            FullScaleRange::Within1_024V => Some(FullScaleRange::Within0_512V),
+            FullScaleRange::Within0_512V => Some(FullScaleRange::Within0_256V),
+            FullScaleRange::Within0_256V => None,
+        }
+    }
+}
+
+/// Limit of the full-scale range at which a measurement was clipped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Clipping {
+    /// The input is at or above the positive full-scale voltage.
+    Positive,
+    /// The input is at or below the negative full-scale voltage.
+    Negative,
+}
+
+/// Measurement flagged with whether it was clipped
+///
+/// See [`read_measurement()`](struct.Ads1x1x.html#method.read_measurement).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    /// Raw measurement
+    pub raw: i16,
+    /// Limit at which the measurement was clipped, `None` if it is within
+    /// the full-scale range
+    pub clipping: Option<Clipping>,
+}
+
+impl Measurement {
+    /// Whether the measurement was clipped, in which case the actual input
+    /// voltage may lie beyond the full-scale range
+    pub fn is_clipped(&self) -> bool {
+        self.clipping.is_some()
+    }
+}
+
+/// Auto-ranging state
+///
+/// This holds the full-scale range to start the next measurement with,
+/// which is the one the previous measurement ended with.
+/// See [`read_auto_range()`](struct.Ads1x1x.html#method.read_auto_range).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoRange {
+    pub(crate) full_scale_range: FullScaleRange,
+    pub(crate) step_down_fraction: f32,
+}
+
+impl AutoRange {
+    /// Start with the given full-scale range
+    ///
+    /// The range is stepped down when a measurement uses less than 40% of it.
+    pub fn new(start: FullScaleRange) -> Self {
+        AutoRange {
+            full_scale_range: start,
+            step_down_fraction: 0.4,
+        }
+    }
+
+    /// Step the range down when a measurement uses less than the given
+    /// fraction of it
+    ///
+    /// As each range is half as large as the next wider one, fractions
+    /// below 0.5 keep the measurement within the narrower range.
+    pub fn with_step_down_fraction(self, fraction: f32) -> Self {
+        AutoRange {
+            step_down_fraction: fraction,
+            ..self
+        }
+    }
+
+    /// Get the full-scale range the next measurement starts with
+    pub fn full_scale_range(&self) -> FullScaleRange {
+        self.full_scale_range
+    }
+}
+
+/// Measurement made with auto-ranging
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoRangeMeasurement {
+    /// Raw measurement
+    pub raw: i16,
+    /// Measurement in volts
+    pub volts: f32,
+    /// Full-scale range the measurement was made with
+    pub full_scale_range: FullScaleRange,
+}
+
 /// Possible slave addresses
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SlaveAddr {
@@ -179,6 +504,16 @@ impl SlaveAddr {
         }
     }
 
+    /// Inverse of `addr()`. Returns `None` if the address does not belong
+    /// to the addresses selectable with the `ADDR` pin.
+    pub(crate) fn from_addr(address: u8, default: u8) -> Option<Self> {
+        match address ^ default {
+            0 => Some(SlaveAddr::Default),
+            bits if bits <= 0b11 => Some(SlaveAddr::Alternative(bits & 0b10 != 0, bits & 1 != 0)),
+            _ => None,
+        }
+    }
+
     /// Create `SlaveAddr` instance corresponding to the address
     /// effective when connecting the pin `ADDR` to GND (0x48).
     ///
@@ -212,6 +547,171 @@ impl SlaveAddr {
     }
 }
 
+/// Settings applied whenever a channel is selected
+///
+/// See [`set_channel_profile()`](struct.Ads1x1x.html#method.set_channel_profile).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelProfile<DR> {
+    /// Full-scale range to measure the channel with
+    pub full_scale_range: FullScaleRange,
+    /// Data rate to measure the channel with
+    pub data_rate: DR,
+}
+
+/// Channel profile with the data rate encoded as DR bits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct StoredChannelProfile {
+    pub(crate) full_scale_range: FullScaleRange,
+    pub(crate) data_rate_bits: u8,
+}
+
+/// Time from which on a conversion result is fresh in continuous mode
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FreshSample {
+    /// Configuration the time was calculated with
+    pub(crate) config: Config,
+    /// Wrapping timestamp in microseconds
+    pub(crate) at_us: u32,
+}
+
+/// Step of a [`Sequence`](struct.Sequence.html)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SequenceStep {
+    /// Channel to measure
+    pub channel: ChannelSelection,
+    /// Full-scale range to measure with, `None` to keep the current one
+    pub full_scale_range: Option<FullScaleRange>,
+}
+
+impl SequenceStep {
+    /// Measure the channel with the current full-scale range
+    pub fn new(channel: ChannelSelection) -> Self {
+        SequenceStep {
+            channel,
+            full_scale_range: None,
+        }
+    }
+
+    /// Measure with the given full-scale range
+    ///
+    /// Only available in the ADS1x14 and ADS1x15.
+    pub fn with_full_scale_range(self, range: FullScaleRange) -> Self {
+        SequenceStep {
+            full_scale_range: Some(range),
+            ..self
+        }
+    }
+}
+
+impl From<ChannelSelection> for SequenceStep {
+    fn from(channel: ChannelSelection) -> Self {
+        SequenceStep::new(channel)
+    }
+}
+
+/// Sequence of one-shot measurements made back-to-back
+///
+/// This also holds the progress of the sequence when it is read with
+/// [`read_sequence()`](struct.Ads1x1x.html#method.read_sequence).
+#[derive(Debug, Clone)]
+pub struct Sequence<const N: usize> {
+    pub(crate) steps: [SequenceStep; N],
+    pub(crate) results: [i16; N],
+    pub(crate) next: usize,
+    pub(crate) started: bool,
+}
+
+impl<const N: usize> Sequence<N> {
+    /// Create a sequence measuring the given steps in order
+    pub fn new(steps: [SequenceStep; N]) -> Self {
+        Sequence {
+            steps,
+            results: [0; N],
+            next: 0,
+            started: false,
+        }
+    }
+
+    /// Create a sequence measuring the given channels in order with the
+    /// current full-scale range
+    pub fn from_channels(channels: [ChannelSelection; N]) -> Self {
+        Sequence::new(channels.map(SequenceStep::new))
+    }
+
+    /// Get the steps of this sequence
+    pub fn steps(&self) -> &[SequenceStep; N] {
+        &self.steps
+    }
+
+    /// Abandon the measurement in progress so that the next read starts
+    /// from the first step.
+    pub fn reset(&mut self) {
+        self.next = 0;
+        self.started = false;
+    }
+}
+
+/// List of channels measured in turn in continuous-conversion mode
+///
+/// This also holds the channel to be measured next.
+/// See [`read_scan()`](struct.Ads1x1x.html#method.read_scan).
+#[derive(Debug, Clone)]
+pub struct ChannelScan<const N: usize> {
+    pub(crate) channels: [ChannelSelection; N],
+    pub(crate) next: usize,
+}
+
+impl<const N: usize> ChannelScan<N> {
+    /// Create a scan measuring the given channels in turn
+    pub fn new(channels: [ChannelSelection; N]) -> Self {
+        ChannelScan { channels, next: 0 }
+    }
+
+    /// Get the channels of this scan
+    pub fn channels(&self) -> &[ChannelSelection; N] {
+        &self.channels
+    }
+
+    /// Get the channel to be measured next
+    ///
+    /// Returns `None` if the scan has no channels.
+    pub fn next_channel(&self) -> Option<ChannelSelection> {
+        self.channels.get(self.next).copied()
+    }
+
+    pub(crate) fn advance(&mut self) {
+        self.next = (self.next + 1) % N;
+    }
+}
+
+/// Device configuration as read from the configuration register
+///
+/// `DR` is the data rate type of the device: [`DataRate12Bit`](enum.DataRate12Bit.html)
+/// for ADS101x devices and [`DataRate16Bit`](enum.DataRate16Bit.html) for ADS111x devices.
+///
+/// The input multiplexer and the programmable gain amplifier are only present
+/// in some devices. For other devices the corresponding fields reflect the
+/// register contents but have no effect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Configuration<DR> {
+    /// Input channel selection
+    pub channel: ChannelSelection,
+    /// Full-scale range of the programmable gain amplifier
+    pub full_scale_range: FullScaleRange,
+    /// Operating mode
+    pub operating_mode: OperatingMode,
+    /// Data rate
+    pub data_rate: DR,
+    /// Comparator mode
+    pub comparator_mode: ComparatorMode,
+    /// Comparator polarity
+    pub comparator_polarity: ComparatorPolarity,
+    /// Comparator latching
+    pub comparator_latching: ComparatorLatching,
+    /// Comparator alert queue, `None` if the comparator is disabled
+    pub comparator_queue: Option<ComparatorQueue>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Config {
     pub(crate) bits: u16,
@@ -235,28 +735,65 @@ impl Config {
     }
 }
 
+impl Config {
+    pub(crate) fn decode<CONV: ic::Resolution>(&self) -> Configuration<CONV::DataRate> {
+        Configuration {
+            channel: self.channel(),
+            full_scale_range: self.full_scale_range(),
+            operating_mode: self.operating_mode(),
+            data_rate: CONV::data_rate(self.data_rate_bits()),
+            comparator_mode: self.comparator_mode(),
+            comparator_polarity: self.comparator_polarity(),
+            comparator_latching: self.comparator_latching(),
+            comparator_queue: self.comparator_queue(),
+        }
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Config { bits: 0x8583 }
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for FullScaleRange {
     fn default() -> Self {
         FullScaleRange::Within2_048V
     }
 }
 
+/// Configuration builder
+///
+/// Accumulates configuration changes which are then written to the device
+/// at once. See [`configure()`](struct.Ads1x1x.html#method.configure).
+#[derive(Debug)]
+pub struct ConfigBuilder<IC, CONV, ALERT = alert::Disabled> {
+    pub(crate) config: Config,
+    pub(crate) _ic: PhantomData<IC>,
+    pub(crate) _conv: PhantomData<CONV>,
+    pub(crate) _alert: PhantomData<ALERT>,
+}
+
 /// ADS1x1x ADC driver
+///
+/// `MODE` is the operating mode, see [`mode`](mode/index.html), and `ALERT`
+/// the role of the ALERT/RDY pin, see [`alert`](alert/index.html).
 #[derive(Debug, Default)]
-pub struct Ads1x1x<DI, IC, CONV, MODE> {
+pub struct Ads1x1x<DI, IC, CONV, MODE, ALERT = alert::Disabled> {
     pub(crate) iface: DI,
     pub(crate) config: Config,
     pub(crate) fsr: FullScaleRange,
     pub(crate) a_conversion_was_started: bool,
+    pub(crate) conversion_poll_limit: Option<u32>,
+    pub(crate) conversion_polls: u32,
+    pub(crate) channel_profiles: [Option<StoredChannelProfile>; ChannelSelection::COUNT],
+    pub(crate) fresh_sample: Option<FreshSample>,
+    pub(crate) out_of_range_error: bool,
     pub(crate) _conv: PhantomData<CONV>,
     pub(crate) _ic: PhantomData<IC>,
     pub(crate) _mode: PhantomData<MODE>,
+    pub(crate) _alert: PhantomData<ALERT>,
 }
 
 /// Multi channel One-shot ADC
@@ -270,8 +807,33 @@ pub trait DynamicOneShot: private::Sealed {
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+    use self::std::string::ToString;
     use crate::DEVICE_BASE_ADDRESS as ADDR;
-    use crate::{FullScaleRange, SlaveAddr};
+    use crate::{DeviceRegister, Error, FullScaleRange, ModeChangeError, Operation, SlaveAddr};
+
+    #[test]
+    fn can_display_error() {
+        let error = Error::I2C(5, Operation::WriteRegister(DeviceRegister::HighThreshold));
+        assert_eq!(
+            "I2C error while writing the high threshold register: 5",
+            error.to_string()
+        );
+        assert_eq!(
+            "conversion did not finish in time",
+            Error::<()>::Timeout.to_string()
+        );
+        assert_eq!(
+            "measurement out of range",
+            Error::<()>::OutOfRange.to_string()
+        );
+    }
+
+    #[test]
+    fn can_debug_mode_change_error() {
+        let error = ModeChangeError::I2C(5, ());
+        assert_eq!("I2C(5, ..)", std::format!("{:?}", error));
+    }
 
     #[test]
     fn can_get_default_address() {
@@ -0,0 +1,735 @@
+use ads1x1x::{
+    bus, channel, Ads1x1x, AutoRange, ChannelScan, ChannelSelection, Clipping, ComparatorPolarity,
+    ComparatorQueue, DataRate16Bit, Error, FullScaleRange, Sequence, SequenceStep, SlaveAddr,
+};
+use core::{
+    future::{self, Future},
+    pin::pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+use embedded_hal_async::digital::Wait;
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    digital::{Mock as PinMock, State as PinState, Transaction as PinTrans},
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+
+mod common;
+use crate::common::{BitFlags as BF, Config, Register, DEVICE_ADDRESS as DEV_ADDR};
+
+fn noop_raw_waker() -> RawWaker {
+    fn clone(_: *const ()) -> RawWaker {
+        noop_raw_waker()
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    RawWaker::new(core::ptr::null(), &VTABLE)
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    // The mocks never return `Poll::Pending`, so the waker is never used.
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn can_create_and_destroy() {
+    let dev = Ads1x1x::new_ads1115_async(I2cMock::new(&[]), SlaveAddr::default());
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_set_data_rate() {
+    let config = Config::default()
+        .with_high(BF::DR2)
+        .with_high(BF::DR1)
+        .with_high(BF::DR0);
+    let transactions = [I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config.msb(), config.lsb()],
+    )];
+    let mut dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    block_on(dev.set_data_rate(DataRate16Bit::Sps860)).unwrap();
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_set_full_scale_range() {
+    let config = Config::default()
+        .with_low(BF::PGA2)
+        .with_low(BF::PGA1)
+        .with_high(BF::PGA0);
+    let transactions = [I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config.msb(), config.lsb()],
+    )];
+    let mut dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    block_on(dev.set_full_scale_range(FullScaleRange::Within4_096V)).unwrap();
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_set_low_threshold() {
+    let transactions = [I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::LOW_TH, 0x7F, 0xF0],
+    )];
+    let mut dev = Ads1x1x::new_ads1015_async(I2cMock::new(&transactions), SlaveAddr::default());
+    block_on(dev.set_low_threshold_raw(2047)).unwrap();
+    dev.destroy_ads1015().done();
+}
+
+#[test]
+fn can_set_threshold_window_voltage() {
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0xA2, 0x40]),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x5D, 0xC0]),
+    ];
+    let mut dev = Ads1x1x::new_ads1015_async(I2cMock::new(&transactions), SlaveAddr::default());
+    block_on(dev.set_threshold_window_voltage(-1.5, 1.5)).unwrap();
+    dev.destroy_ads1015().done();
+}
+
+#[test]
+fn can_read_high_threshold() {
+    let transactions = [I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::HIGH_TH],
+        vec![0x80, 0x00],
+    )];
+    let mut dev = Ads1x1x::new_ads1015_async(I2cMock::new(&transactions), SlaveAddr::default());
+    assert_eq!(-2048, block_on(dev.high_threshold_raw()).unwrap());
+    dev.destroy_ads1015().done();
+}
+
+#[test]
+fn can_configure() {
+    let config = Config::default()
+        .with_high(BF::DR1)
+        .with_low(BF::PGA1)
+        .with_high(BF::COMP_POL);
+    let transactions = [I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config.msb(), config.lsb()],
+    )];
+    let mut dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    block_on(dev.configure(|c| {
+        c.data_rate(DataRate16Bit::Sps475)
+            .full_scale_range(FullScaleRange::Within6_144V)
+            .comparator_polarity(ComparatorPolarity::ActiveHigh)
+    }))
+    .unwrap();
+    assert_eq!(FullScaleRange::Within6_144V, dev.full_scale_range());
+    dev.destroy_ads1115().done();
+}
+
+fn measurement_transactions() -> Vec<I2cTrans> {
+    let default_config = Config::default();
+    let config_in_progress = Config::default().with_low(BF::OS);
+    vec![
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, default_config.msb(), default_config.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_in_progress.msb(), config_in_progress.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ]
+}
+
+#[test]
+fn can_measure() {
+    let transactions = measurement_transactions();
+    let mut dev = Ads1x1x::new_ads1013_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let measurement = block_on(dev.read(&mut channel::DifferentialA0A1)).unwrap();
+    assert_eq!(-2048, measurement);
+    dev.destroy_ads1013().done();
+}
+
+#[test]
+fn can_measure_with_delay() {
+    let transactions = measurement_transactions();
+    let mut dev = Ads1x1x::new_ads1113_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let measurement =
+        block_on(dev.read_with_delay(&mut channel::DifferentialA0A1, &mut NoopDelay)).unwrap();
+    assert_eq!(-32768, measurement);
+    dev.destroy_ads1113().done();
+}
+
+#[test]
+fn can_read_sequence() {
+    let config_a0 = Config::default().with_high(BF::MUX2);
+    let config_a1 = config_a0
+        .with_high(BF::MUX0)
+        .with_low(BF::PGA1)
+        .with_high(BF::PGA0);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xFF]),
+    ];
+    let mut dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let sequence = Sequence::new([
+        SequenceStep::new(ChannelSelection::SingleA0),
+        SequenceStep::new(ChannelSelection::SingleA1)
+            .with_full_scale_range(FullScaleRange::Within4_096V),
+    ]);
+    let measurements = block_on(dev.read_sequence(&sequence)).unwrap();
+    assert_eq!([-32768, 32767], measurements);
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_read_scan() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let config_a3 = config
+        .with_high(BF::MUX2)
+        .with_high(BF::MUX1)
+        .with_high(BF::MUX0);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a3.msb(), config_a3.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let mut dev = block_on(dev.into_continuous()).ok().unwrap();
+    let mut scan = ChannelScan::new([ChannelSelection::SingleA3]);
+    let result = block_on(dev.read_scan(&mut scan, &mut NoopDelay)).unwrap();
+    assert_eq!((ChannelSelection::SingleA3, -32768), result);
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_flag_clipped_measurements() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let mut dev = block_on(dev.into_continuous()).ok().unwrap();
+    let measurement = block_on(dev.read_measurement()).unwrap();
+    assert_eq!(Some(Clipping::Negative), measurement.clipping);
+    dev.set_out_of_range_error(true);
+    match block_on(dev.read()) {
+        Err(Error::OutOfRange) => (),
+        _ => panic!("OutOfRange error was not returned."),
+    }
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_read_auto_range() {
+    let config_0_256 = Config::default()
+        .with_high(BF::PGA2)
+        .with_low(BF::PGA1)
+        .with_high(BF::PGA0);
+    let config_0_512 = Config::default().with_high(BF::PGA2).with_low(BF::PGA1);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_0_256.msb(), config_0_256.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_0_256.msb(), config_0_256.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_0_256.msb(), config_0_256.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xFF]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_0_512.msb(), config_0_512.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_0_512.msb(), config_0_512.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x4E, 0x20]),
+    ];
+    let mut dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let mut auto_range = AutoRange::new(FullScaleRange::Within0_256V);
+    let measurement =
+        block_on(dev.read_auto_range(&mut channel::DifferentialA0A1, &mut auto_range)).unwrap();
+    assert_eq!(20000, measurement.raw);
+    assert_eq!(FullScaleRange::Within0_512V, measurement.full_scale_range);
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn read_times_out() {
+    let default_config = Config::default();
+    let config_in_progress = Config::default().with_low(BF::OS);
+    let poll_in_progress = I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config_in_progress.msb(), config_in_progress.lsb()],
+    );
+    let trigger = I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, default_config.msb(), default_config.lsb()],
+    );
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        trigger.clone(),
+        poll_in_progress.clone(),
+        poll_in_progress,
+        trigger,
+    ];
+    let mut dev = Ads1x1x::new_ads1013_async(I2cMock::new(&transactions), SlaveAddr::default());
+    dev.set_conversion_poll_limit(Some(2));
+    match block_on(dev.read(&mut channel::DifferentialA0A1)) {
+        Err(Error::Timeout) => (),
+        _ => panic!("Timeout error was not returned."),
+    }
+    dev.destroy_ads1013().done();
+}
+
+#[test]
+fn read_times_out_by_default() {
+    let default_config = Config::default();
+    let config_in_progress = Config::default().with_low(BF::OS);
+    let poll_in_progress = I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config_in_progress.msb(), config_in_progress.lsb()],
+    );
+    let trigger = I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, default_config.msb(), default_config.lsb()],
+    );
+    let mut transactions = vec![
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        trigger.clone(),
+    ];
+    // 16 retries plus polling for the 688 us conversion time at 1600 SPS
+    transactions.extend(std::iter::repeat(poll_in_progress).take(16 + 68));
+    transactions.push(trigger);
+    let mut dev = Ads1x1x::new_ads1013_async(I2cMock::new(&transactions), SlaveAddr::default());
+    match block_on(dev.read(&mut channel::DifferentialA0A1)) {
+        Err(Error::Timeout) => (),
+        _ => panic!("Timeout error was not returned."),
+    }
+    dev.destroy_ads1013().done();
+}
+
+#[test]
+fn read_detects_device_reset() {
+    let config = Config::default()
+        .with_high(BF::DR2)
+        .with_high(BF::DR1)
+        .with_high(BF::DR0);
+    let default_config = Config::default();
+    let trigger = I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]);
+    let transactions = [
+        trigger.clone(),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+        trigger.clone(),
+        // the device was reset during the conversion
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        trigger,
+    ];
+    let mut dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    block_on(dev.set_data_rate(DataRate16Bit::Sps860)).unwrap();
+    match block_on(dev.read(&mut channel::DifferentialA0A1)) {
+        Err(Error::ConfigurationChanged) => (),
+        _ => panic!("ConfigurationChanged error was not returned."),
+    }
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_measure_continuous() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let config_a3 = config
+        .with_high(BF::MUX2)
+        .with_high(BF::MUX1)
+        .with_high(BF::MUX0);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a3.msb(), config_a3.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![
+                Register::CONFIG,
+                config_a3.with_high(BF::OP_MODE).msb(),
+                config_a3.with_high(BF::OP_MODE).lsb(),
+            ],
+        ),
+    ];
+    let dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let mut dev = block_on(dev.into_continuous()).ok().unwrap();
+    block_on(dev.select_channel(&mut channel::SingleA3)).unwrap();
+    assert_eq!(-32768, block_on(dev.read()).unwrap());
+    let dev = block_on(dev.into_one_shot()).ok().unwrap();
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_sync_configuration_from_device() {
+    let config = Config::default().with_low(BF::PGA1);
+    let config_fsr = config.with_high(BF::PGA0);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_fsr.msb(), config_fsr.lsb()],
+        ),
+    ];
+    let mut dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let configuration = block_on(dev.sync_from_device()).unwrap();
+    assert_eq!(FullScaleRange::Within6_144V, configuration.full_scale_range);
+    block_on(dev.set_full_scale_range(FullScaleRange::Within4_096V)).unwrap();
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn sync_fails_if_comparator_state_does_not_match() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let transactions = [I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config.msb(), config.lsb()],
+    )];
+    let mut dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    match block_on(dev.sync_from_device()) {
+        Err(Error::ComparatorMismatch) => (),
+        _ => panic!("ComparatorMismatch error was not returned."),
+    }
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_reset_through_general_call() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(0x00, vec![0x06]),
+    ];
+    let dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let dev = block_on(dev.into_continuous()).ok().unwrap();
+    let dev = block_on(dev.general_call_reset()).ok().unwrap();
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_read_alert_response() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::read(0x0C, vec![DEV_ADDR << 1]),
+    ];
+    let dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let mut dev = block_on(dev.enable_comparator(ComparatorQueue::One))
+        .ok()
+        .unwrap();
+    assert_eq!(
+        Some(SlaveAddr::default()),
+        block_on(dev.read_alert_response()).unwrap()
+    );
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_change_alert_rdy_pin_role() {
+    let config_two = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_high(BF::COMP_QUE0);
+    let config = Config::default();
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_two.msb(), config_two.lsb()],
+        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x80, 0x00]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0x00, 0x00]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_two.msb(), config_two.lsb()],
+        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+    ];
+    let dev = Ads1x1x::new_ads1115_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let dev = block_on(dev.enable_comparator(ComparatorQueue::Two))
+        .ok()
+        .unwrap();
+    let dev = block_on(dev.use_alert_rdy_pin_as_ready()).ok().unwrap();
+    let dev = block_on(dev.disable_comparator()).ok().unwrap();
+    dev.destroy_ads1115().done();
+}
+
+#[test]
+fn can_measure_with_ready_pin() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0)
+        .with_high(BF::COMP_POL);
+    let config_pol = Config::default().with_high(BF::COMP_POL);
+    let config_os = config.with_high(BF::OS);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_pol.msb(), config_pol.lsb()],
+        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x80, 0x00]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0x00, 0x00]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut pin = PinMock::new(&[PinTrans::wait_for_state(PinState::High)]);
+    let mut dev = Ads1x1x::new_ads1014_async(I2cMock::new(&transactions), SlaveAddr::default());
+    block_on(dev.set_comparator_polarity(ComparatorPolarity::ActiveHigh)).unwrap();
+    let mut dev = block_on(dev.use_alert_rdy_pin_as_ready()).ok().unwrap();
+    let measurement =
+        block_on(dev.read_with_ready_pin(&mut channel::DifferentialA0A1, &mut pin, &mut NoopDelay))
+            .unwrap();
+    assert_eq!(-2048, measurement);
+    dev.destroy_ads1014().done();
+    pin.done();
+}
+
+/// Pin which never signals the end of a conversion
+struct DisconnectedPin;
+
+impl embedded_hal_1::digital::ErrorType for DisconnectedPin {
+    type Error = core::convert::Infallible;
+}
+
+impl Wait for DisconnectedPin {
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        future::pending().await
+    }
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        future::pending().await
+    }
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        future::pending().await
+    }
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        future::pending().await
+    }
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        future::pending().await
+    }
+}
+
+fn ready_pin_transactions() -> Vec<I2cTrans> {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config_os = config.with_high(BF::OS);
+    vec![
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x80, 0x00]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0x00, 0x00]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+        ),
+    ]
+}
+
+#[test]
+fn read_with_ready_pin_falls_back_to_polling() {
+    let config_os = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0)
+        .with_high(BF::OS);
+    let mut transactions = ready_pin_transactions();
+    transactions.extend([
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ]);
+    let dev = Ads1x1x::new_ads1014_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let mut dev = block_on(dev.use_alert_rdy_pin_as_ready()).ok().unwrap();
+    let measurement = block_on(dev.read_with_ready_pin(
+        &mut channel::DifferentialA0A1,
+        &mut DisconnectedPin,
+        &mut NoopDelay,
+    ))
+    .unwrap();
+    assert_eq!(-2048, measurement);
+    dev.destroy_ads1014().done();
+}
+
+#[test]
+fn read_with_ready_pin_times_out() {
+    let config_os = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0)
+        .with_high(BF::OS);
+    let config_in_progress = config_os.with_low(BF::OS);
+    let poll_in_progress = I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config_in_progress.msb(), config_in_progress.lsb()],
+    );
+    let mut transactions = ready_pin_transactions();
+    let trigger = transactions.last().unwrap().clone();
+    transactions.extend([poll_in_progress.clone(), poll_in_progress, trigger]);
+    let dev = Ads1x1x::new_ads1014_async(I2cMock::new(&transactions), SlaveAddr::default());
+    let mut dev = block_on(dev.use_alert_rdy_pin_as_ready()).ok().unwrap();
+    dev.set_conversion_poll_limit(Some(2));
+    match block_on(dev.read_with_ready_pin(
+        &mut channel::DifferentialA0A1,
+        &mut DisconnectedPin,
+        &mut NoopDelay,
+    )) {
+        Err(Error::Timeout) => (),
+        _ => panic!("Timeout error was not returned."),
+    }
+    dev.destroy_ads1014().done();
+}
+
+#[test]
+fn can_scan_bus() {
+    let config = Config::default();
+    let config_fast = config
+        .with_high(BF::DR2)
+        .with_high(BF::DR1)
+        .with_high(BF::DR0);
+    let config_no_os = config.with_low(BF::OS);
+    let mut transactions = vec![
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_fast.msb(), config_fast.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_fast.msb(), config_fast.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_no_os.msb(), config_no_os.lsb()],
+        ),
+    ];
+    // nothing is written to devices which are converting
+    let busy = config.with_low(BF::OS);
+    for address in DEV_ADDR + 1..DEV_ADDR + 4 {
+        transactions.push(I2cTrans::write_read(
+            address,
+            vec![Register::CONFIG],
+            vec![busy.msb(), busy.lsb()],
+        ));
+    }
+    let mut i2c = I2cMock::new(&transactions);
+    let found = block_on(bus::scan_async(&mut i2c, &mut NoopDelay));
+    assert_eq!(
+        [
+            Some(bus::ProbedDevice {
+                address: SlaveAddr::new_gnd(),
+                power_up_config: true,
+                resolution: Some(bus::Resolution::Bits12),
+            }),
+            Some(bus::ProbedDevice {
+                address: SlaveAddr::new_vdd(),
+                power_up_config: false,
+                resolution: None,
+            }),
+            Some(bus::ProbedDevice {
+                address: SlaveAddr::new_sda(),
+                power_up_config: false,
+                resolution: None,
+            }),
+            Some(bus::ProbedDevice {
+                address: SlaveAddr::new_scl(),
+                power_up_config: false,
+                resolution: None,
+            }),
+        ],
+        found
+    );
+    i2c.done();
+}
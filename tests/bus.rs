@@ -0,0 +1,243 @@
+use ads1x1x::{bus, Ads1x1x, SlaveAddr, Variant};
+use core::cell::RefCell;
+
+mod common;
+use crate::common::{
+    i2c_error, BitFlags as BF, Config, I2cMock, I2cTrans, NoopDelay, Register,
+    DEVICE_ADDRESS as DEV_ADDR,
+};
+
+const ALERT_RESPONSE_ADDRESS: u8 = 0x0C;
+
+#[test]
+fn can_read_alert_response() {
+    let transactions = [
+        I2cTrans::read(ALERT_RESPONSE_ADDRESS, vec![0x4A << 1]),
+        I2cTrans::read(ALERT_RESPONSE_ADDRESS, vec![0x4B << 1]),
+    ];
+    let mut i2c = I2cMock::new(&transactions);
+    assert_eq!(
+        Some(SlaveAddr::new_sda()),
+        bus::read_alert_response(&mut i2c).unwrap()
+    );
+    assert_eq!(
+        Some(SlaveAddr::new_scl()),
+        bus::read_alert_response(&mut i2c).unwrap()
+    );
+    i2c.done();
+}
+
+#[test]
+fn alert_response_of_other_device_is_none() {
+    let transactions = [I2cTrans::read(ALERT_RESPONSE_ADDRESS, vec![0x10 << 1])];
+    let mut i2c = I2cMock::new(&transactions);
+    assert_eq!(None, bus::read_alert_response(&mut i2c).unwrap());
+    i2c.done();
+}
+
+#[test]
+fn alert_response_without_alerting_device_fails() {
+    let transactions = [I2cTrans::read(ALERT_RESPONSE_ADDRESS, vec![0]).with_error(i2c_error())];
+    let mut i2c = I2cMock::new(&transactions);
+    assert!(bus::read_alert_response(&mut i2c).is_err());
+    i2c.done();
+}
+
+fn missing_device(address: u8) -> I2cTrans {
+    I2cTrans::write_read(address, vec![Register::CONFIG], vec![0, 0]).with_error(i2c_error())
+}
+
+/// Transactions for timing a conversion at the highest data rate on a
+/// device holding the power-up configuration.
+fn resolution_probe_transactions(address: u8, in_progress: bool) -> Vec<I2cTrans> {
+    let config = Config::default();
+    let config_fast = config
+        .with_high(BF::DR2)
+        .with_high(BF::DR1)
+        .with_high(BF::DR0);
+    let config_after = if in_progress {
+        config_fast.with_low(BF::OS)
+    } else {
+        config_fast.with_high(BF::OS)
+    };
+    let config_no_os = config.with_low(BF::OS);
+    vec![
+        I2cTrans::write(
+            address,
+            vec![Register::CONFIG, config_fast.msb(), config_fast.lsb()],
+        ),
+        I2cTrans::write_read(
+            address,
+            vec![Register::CONFIG],
+            vec![config_after.msb(), config_after.lsb()],
+        ),
+        I2cTrans::write(
+            address,
+            vec![Register::CONFIG, config_no_os.msb(), config_no_os.lsb()],
+        ),
+    ]
+}
+
+#[test]
+fn can_scan_bus() {
+    let config = Config::default();
+    let config_cont = Config::default().with_low(BF::OP_MODE);
+    let mut transactions = vec![I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config.msb(), config.lsb()],
+    )];
+    transactions.extend(resolution_probe_transactions(DEV_ADDR, false));
+    transactions.push(I2cTrans::write_read(
+        DEV_ADDR + 1,
+        vec![Register::CONFIG],
+        vec![config.msb(), config.lsb()],
+    ));
+    transactions.extend(resolution_probe_transactions(DEV_ADDR + 1, true));
+    transactions.push(I2cTrans::write_read(
+        DEV_ADDR + 2,
+        vec![Register::CONFIG],
+        vec![config_cont.msb(), config_cont.lsb()],
+    ));
+    for value in [0x12, 0x34, 0x56, 0x78] {
+        transactions.push(I2cTrans::write_read(
+            DEV_ADDR + 2,
+            vec![Register::CONVERSION],
+            vec![0x01, value],
+        ));
+    }
+    transactions.push(missing_device(DEV_ADDR + 3));
+    let mut i2c = I2cMock::new(&transactions);
+    let found = bus::scan(&mut i2c, &mut NoopDelay);
+    assert_eq!(
+        [
+            Some(bus::ProbedDevice {
+                address: SlaveAddr::new_gnd(),
+                power_up_config: true,
+                resolution: Some(bus::Resolution::Bits12),
+            }),
+            Some(bus::ProbedDevice {
+                address: SlaveAddr::new_vdd(),
+                power_up_config: true,
+                resolution: Some(bus::Resolution::Bits16),
+            }),
+            Some(bus::ProbedDevice {
+                address: SlaveAddr::new_sda(),
+                power_up_config: false,
+                resolution: Some(bus::Resolution::Bits16),
+            }),
+            None,
+        ],
+        found
+    );
+    i2c.done();
+}
+
+#[test]
+fn scan_does_not_write_to_other_devices() {
+    // TMP102 temperature sensor configuration in shutdown mode
+    let other = 0x61A0_u16;
+    // conversion in progress in single-shot mode
+    let busy = Config::default().with_low(BF::OS);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![(other >> 8) as u8, other as u8],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR + 1,
+            vec![Register::CONFIG],
+            vec![busy.msb(), busy.lsb()],
+        ),
+        missing_device(DEV_ADDR + 2),
+        missing_device(DEV_ADDR + 3),
+    ];
+    let mut i2c = I2cMock::new(&transactions);
+    let found = bus::scan(&mut i2c, &mut NoopDelay);
+    assert_eq!(None, found[0].unwrap().resolution);
+    assert_eq!(None, found[1].unwrap().resolution);
+    i2c.done();
+}
+
+#[test]
+fn scan_does_not_sample_other_devices() {
+    // TMP102 temperature sensor power-up configuration
+    let other = 0x60A0_u16;
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![(other >> 8) as u8, other as u8],
+        ),
+        missing_device(DEV_ADDR + 1),
+        missing_device(DEV_ADDR + 2),
+        missing_device(DEV_ADDR + 3),
+    ];
+    let mut i2c = I2cMock::new(&transactions);
+    let found = bus::scan(&mut i2c, &mut NoopDelay);
+    assert_eq!(None, found[0].unwrap().resolution);
+    i2c.done();
+}
+
+fn detection_transactions(in_progress: bool) -> Vec<I2cTrans> {
+    let config = Config::default();
+    let mut transactions = vec![I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config.msb(), config.lsb()],
+    )];
+    transactions.extend(resolution_probe_transactions(DEV_ADDR, in_progress));
+    transactions
+}
+
+#[test]
+fn can_detect_16bit_device() {
+    let transactions = detection_transactions(true);
+    let mut i2c = I2cMock::new(&transactions);
+    let resolution =
+        bus::detect_resolution(&mut i2c, SlaveAddr::default(), &mut NoopDelay).unwrap();
+    assert_eq!(Some(bus::Resolution::Bits16), resolution);
+    assert_eq!(
+        Variant::Ads1115,
+        Variant::from_features(resolution.unwrap(), true, true)
+    );
+    i2c.done();
+}
+
+#[test]
+fn can_detect_12bit_device() {
+    let transactions = detection_transactions(false);
+    let mut i2c = I2cMock::new(&transactions);
+    let resolution =
+        bus::detect_resolution(&mut i2c, SlaveAddr::default(), &mut NoopDelay).unwrap();
+    assert_eq!(Some(bus::Resolution::Bits12), resolution);
+    assert_eq!(
+        Variant::Ads1013,
+        Variant::from_features(resolution.unwrap(), false, true)
+    );
+    i2c.done();
+}
+
+#[test]
+fn can_share_bus() {
+    let config = Config::default().with_high(BF::DR0);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(
+            DEV_ADDR + 3,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+    ];
+    let i2c = RefCell::new(I2cMock::new(&transactions));
+    let mut adc_gnd = Ads1x1x::new_ads1115(bus::RefCellBus::new(&i2c), SlaveAddr::new_gnd());
+    let mut adc_scl = Ads1x1x::new_ads1115(bus::RefCellBus::new(&i2c), SlaveAddr::new_scl());
+    adc_gnd
+        .set_data_rate(ads1x1x::DataRate16Bit::Sps250)
+        .unwrap();
+    assert!(!adc_scl.is_measurement_in_progress().unwrap());
+    adc_gnd.destroy_ads1115();
+    adc_scl.destroy_ads1115();
+    i2c.into_inner().done();
+}
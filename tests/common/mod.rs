@@ -1,6 +1,18 @@
 extern crate embedded_hal_mock as hal;
-use self::hal::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+#[cfg(feature = "eh02")]
+#[allow(unused)]
+pub use self::hal::eh0::{
+    delay::NoopDelay,
+    digital::{Mock as PinMock, State as PinState, Transaction as PinTrans},
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+#[cfg(feature = "eh02")]
+#[allow(unused)]
+pub fn i2c_error() -> hal::eh0::MockError {
+    hal::eh0::MockError::Io(std::io::ErrorKind::Other)
+}
 extern crate ads1x1x;
+#[cfg(feature = "eh02")]
 use self::ads1x1x::{ic, interface, mode, Ads1x1x, SlaveAddr};
 
 #[allow(unused)]
@@ -70,13 +82,15 @@ impl Default for Config {
 
 macro_rules! impl_new_destroy {
     ($ic:ident, $create:ident, $destroy:ident, $conv:ty, $trans:ty, $iface:ty) => {
+        #[cfg(feature = "eh02")]
         #[allow(unused)]
         pub fn $create(transactions: &[$trans]) -> Ads1x1x<$iface, ic::$ic, $conv, mode::OneShot> {
             Ads1x1x::$create(I2cMock::new(transactions), SlaveAddr::default())
         }
 
+        #[cfg(feature = "eh02")]
         #[allow(unused)]
-        pub fn $destroy<MODE>(dev: Ads1x1x<$iface, ic::$ic, $conv, MODE>) {
+        pub fn $destroy<MODE, ALERT>(dev: Ads1x1x<$iface, ic::$ic, $conv, MODE, ALERT>) {
             dev.$destroy().done();
         }
     };
@@ -0,0 +1,58 @@
+use ads1x1x::{
+    ChannelSelection, DynamicAds1x1x, DynamicOneShot, Error, FullScaleRange, SlaveAddr, Variant,
+};
+use nb::block;
+
+mod common;
+use crate::common::{
+    BitFlags as BF, Config, I2cMock, I2cTrans, Register, DEVICE_ADDRESS as DEV_ADDR,
+};
+
+#[test]
+fn can_create_and_destroy() {
+    let dev = DynamicAds1x1x::new(I2cMock::new(&[]), SlaveAddr::default(), Variant::Ads1114);
+    assert_eq!(Variant::Ads1114, dev.variant());
+    dev.destroy().done();
+}
+
+#[test]
+fn can_measure() {
+    let default_config = Config::default();
+    let config = Config::default().with_high(BF::MUX2);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut dev = DynamicAds1x1x::new(
+        I2cMock::new(&transactions),
+        SlaveAddr::default(),
+        Variant::Ads1015,
+    );
+    let measurement = block!(dev.read(ChannelSelection::SingleA0)).unwrap();
+    assert_eq!(-2048, measurement);
+    dev.destroy().done();
+}
+
+#[test]
+fn cannot_select_unavailable_channel() {
+    let mut dev = DynamicAds1x1x::new(I2cMock::new(&[]), SlaveAddr::default(), Variant::Ads1113);
+    match dev.read(ChannelSelection::SingleA0) {
+        Err(nb::Error::Other(Error::InvalidInputData)) => (),
+        _ => panic!("InvalidInputData error was not returned."),
+    }
+    match dev.set_full_scale_range(FullScaleRange::Within4_096V) {
+        Err(Error::InvalidInputData) => (),
+        _ => panic!("InvalidInputData error was not returned."),
+    }
+    dev.destroy().done();
+}
@@ -0,0 +1,123 @@
+use ads1x1x::{
+    bus, channel, Ads1x1x, DataRate16Bit, DeviceRegister, Eh1, Error, Operation, SlaveAddr,
+};
+use core::cell::RefCell;
+use embedded_hal::adc::OneShot;
+use embedded_hal_1::i2c::{Error as _, ErrorKind};
+use embedded_hal_mock::eh1::{
+    delay::NoopDelay,
+    i2c::{Mock as I2cMock, Transaction as I2cTrans},
+};
+
+mod common;
+use crate::common::{BitFlags as BF, Config, Register, DEVICE_ADDRESS as DEV_ADDR};
+
+#[test]
+fn can_create_and_destroy() {
+    let dev = Ads1x1x::new_ads1013(Eh1(I2cMock::new(&[])), SlaveAddr::default());
+    let Eh1(mut i2c) = dev.destroy_ads1013();
+    i2c.done();
+}
+
+#[test]
+fn i2c_error_kind_is_forwarded() {
+    let transactions = [
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONFIG], vec![0, 0])
+            .with_error(ErrorKind::Overrun),
+    ];
+    let mut dev = Ads1x1x::new_ads1013(Eh1(I2cMock::new(&transactions)), SlaveAddr::default());
+    let error = dev.read_configuration().unwrap_err();
+    assert_eq!(ErrorKind::Overrun, error.kind());
+    match error {
+        Error::I2C(_, Operation::ReadRegister(DeviceRegister::Config)) => (),
+        _ => panic!("Wrong error returned."),
+    }
+    dev.destroy_ads1013().0.done();
+}
+
+#[test]
+fn can_read_alert_response() {
+    let transactions = [I2cTrans::read(0x0C, vec![0x4A << 1])];
+    let mut i2c = I2cMock::new(&transactions);
+    assert_eq!(
+        Some(SlaveAddr::new_sda()),
+        bus::read_alert_response(&mut Eh1(&mut i2c)).unwrap()
+    );
+    i2c.done();
+}
+
+#[test]
+fn can_share_bus() {
+    let config = Config::default().with_high(BF::DR0);
+    let transactions = [I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config.msb(), config.lsb()],
+    )];
+    let i2c = RefCell::new(I2cMock::new(&transactions));
+    let mut adc = Ads1x1x::new_ads1115(Eh1(bus::RefCellBus::new(&i2c)), SlaveAddr::default());
+    adc.set_data_rate(DataRate16Bit::Sps250).unwrap();
+    adc.destroy_ads1115();
+    i2c.into_inner().done();
+}
+
+#[test]
+fn can_measure() {
+    let default_config = Config::default();
+    let config_os = Config::default().with_high(BF::OS);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut dev = Ads1x1x::new_ads1113(Eh1(I2cMock::new(&transactions)), SlaveAddr::default());
+    let measurement = nb::block!(dev.read(&mut channel::DifferentialA0A1)).unwrap();
+    assert_eq!(-32768, measurement);
+    dev.destroy_ads1113().0.done();
+}
+
+#[test]
+fn can_set_data_rate() {
+    let config = Config::default().with_high(BF::DR0);
+    let transactions = [I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config.msb(), config.lsb()],
+    )];
+    let mut dev = Ads1x1x::new_ads1115(Eh1(I2cMock::new(&transactions)), SlaveAddr::default());
+    dev.set_data_rate(DataRate16Bit::Sps250).unwrap();
+    dev.destroy_ads1115().0.done();
+}
+
+#[test]
+fn can_measure_blocking() {
+    let config_os = Config::default().with_high(BF::OS);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut dev = Ads1x1x::new_ads1113(Eh1(I2cMock::new(&transactions)), SlaveAddr::default());
+    let measurement = dev
+        .read_blocking(&mut channel::DifferentialA0A1, &mut Eh1(NoopDelay))
+        .unwrap();
+    assert_eq!(-32768, measurement);
+    dev.destroy_ads1113().0.done();
+}
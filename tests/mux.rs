@@ -1,11 +1,13 @@
-use ads1x1x::channel;
-use embedded_hal_mock::i2c::Transaction as I2cTrans;
+use ads1x1x::{
+    channel, ChannelProfile, ChannelScan, ChannelSelection, DataRate12Bit, FullScaleRange,
+    Sequence, SequenceStep,
+};
 use nb::block;
 
 mod common;
 use crate::common::{
-    destroy_ads1015 as destroy, new_ads1015 as new, BitFlags as BF, Config, Register,
-    DEVICE_ADDRESS as DEV_ADDR,
+    destroy_ads1015 as destroy, new_ads1015 as new, BitFlags as BF, Config, I2cTrans, NoopDelay,
+    PinMock, PinState, PinTrans, Register, DEVICE_ADDRESS as DEV_ADDR,
 };
 
 macro_rules! mux_test {
@@ -116,3 +118,236 @@ mux_test!(
     SingleA0,
     BF::MUX2
 );
+
+#[test]
+fn read_with_ready_pin_waits_before_changing_channel() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config_os = config.with_high(BF::OS);
+    let config_a2_os = config_os.with_high(BF::MUX1).with_high(BF::MUX0);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a2_os.msb(), config_a2_os.lsb()],
+        ),
+    ];
+    let mut pin = PinMock::new(&[PinTrans::get(PinState::High), PinTrans::get(PinState::Low)]);
+    let dev = new(&transactions);
+    let mut dev = dev.use_alert_rdy_pin_as_ready().ok().unwrap();
+    assert_would_block!(dev.read_with_ready_pin(&mut channel::DifferentialA0A1, &mut pin));
+    assert_would_block!(dev.read_with_ready_pin(&mut channel::DifferentialA2A3, &mut pin));
+    assert_would_block!(dev.read_with_ready_pin(&mut channel::DifferentialA2A3, &mut pin));
+    destroy(dev);
+    pin.done();
+}
+
+fn sequence() -> Sequence<2> {
+    Sequence::new([
+        SequenceStep::new(ChannelSelection::SingleA0),
+        SequenceStep::new(ChannelSelection::SingleA1)
+            .with_full_scale_range(FullScaleRange::Within4_096V),
+    ])
+}
+
+fn sequence_transactions() -> [I2cTrans; 6] {
+    let config_a0 = Config::default().with_high(BF::OS).with_high(BF::MUX2);
+    let config_a1 = config_a0
+        .with_high(BF::MUX0)
+        .with_low(BF::PGA1)
+        .with_high(BF::PGA0);
+    [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a0.msb(), config_a0.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+    ]
+}
+
+#[test]
+fn can_read_sequence() {
+    let transactions = sequence_transactions();
+    let mut dev = new(&transactions);
+    let mut sequence = sequence();
+    let measurements = block!(dev.read_sequence(&mut sequence)).unwrap();
+    assert_eq!([-2048, 2047], measurements);
+    assert_eq!(ChannelSelection::SingleA1, dev.selected_channel());
+    assert_eq!(FullScaleRange::Within4_096V, dev.full_scale_range());
+    destroy(dev);
+}
+
+#[test]
+fn can_read_sequence_blocking() {
+    let transactions = sequence_transactions();
+    let mut dev = new(&transactions);
+    let measurements = dev
+        .read_sequence_blocking(&sequence(), &mut NoopDelay)
+        .unwrap();
+    assert_eq!([-2048, 2047], measurements);
+    destroy(dev);
+}
+
+fn profile() -> ChannelProfile<DataRate12Bit> {
+    ChannelProfile {
+        full_scale_range: FullScaleRange::Within4_096V,
+        data_rate: DataRate12Bit::Sps3300,
+    }
+}
+
+fn profile_config(config: Config) -> Config {
+    config
+        .with_low(BF::PGA1)
+        .with_high(BF::PGA0)
+        .with_high(BF::DR1)
+}
+
+#[test]
+fn can_get_channel_profile() {
+    let mut dev = new(&[]);
+    dev.set_channel_profile(ChannelSelection::SingleA1, Some(profile()));
+    assert_eq!(
+        Some(profile()),
+        dev.channel_profile(ChannelSelection::SingleA1)
+    );
+    assert_eq!(None, dev.channel_profile(ChannelSelection::SingleA0));
+    dev.set_channel_profile(ChannelSelection::SingleA1, None);
+    assert_eq!(None, dev.channel_profile(ChannelSelection::SingleA1));
+    destroy(dev);
+}
+
+#[test]
+fn read_applies_channel_profile() {
+    use embedded_hal::adc::OneShot;
+    let default_config = Config::default();
+    let config = profile_config(
+        Config::default()
+            .with_high(BF::OS)
+            .with_high(BF::MUX2)
+            .with_high(BF::MUX0),
+    );
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut dev = new(&transactions);
+    dev.set_channel_profile(ChannelSelection::SingleA1, Some(profile()));
+    let measurement = block!(dev.read(&mut channel::SingleA1)).unwrap();
+    assert_eq!(-2048, measurement);
+    assert_eq!(FullScaleRange::Within4_096V, dev.full_scale_range());
+    assert_eq!(DataRate12Bit::Sps3300, dev.data_rate());
+    destroy(dev);
+}
+
+#[test]
+fn continuous_select_channel_applies_channel_profile() {
+    let config1 = Config::default().with_low(BF::OP_MODE);
+    let config2 = profile_config(config1.with_high(BF::MUX2).with_high(BF::MUX0));
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config1.msb(), config1.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config2.msb(), config2.lsb()],
+        ),
+    ];
+    let mut dev = new(&transactions);
+    dev.set_channel_profile(ChannelSelection::SingleA1, Some(profile()));
+    let mut dev = dev.into_continuous().ok().unwrap();
+    dev.select_channel(&mut channel::SingleA1).unwrap();
+    assert_eq!(FullScaleRange::Within4_096V, dev.full_scale_range());
+    destroy(dev);
+}
+
+#[test]
+fn can_read_scan() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let config_a0 = config.with_high(BF::MUX2);
+    let config_a1 = config_a0.with_high(BF::MUX0);
+    let write_config_a0 = I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config_a0.msb(), config_a0.lsb()],
+    );
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        write_config_a0.clone(),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_a1.msb(), config_a1.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+        write_config_a0,
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x00, 0x10]),
+    ];
+    let dev = new(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let mut scan = ChannelScan::new([ChannelSelection::SingleA0, ChannelSelection::SingleA1]);
+    let mut delay = NoopDelay;
+    let results = [
+        dev.read_scan(&mut scan, &mut delay).unwrap(),
+        dev.read_scan(&mut scan, &mut delay).unwrap(),
+        dev.read_scan(&mut scan, &mut delay).unwrap(),
+    ];
+    assert_eq!(
+        [
+            (ChannelSelection::SingleA0, -2048),
+            (ChannelSelection::SingleA1, 2047),
+            (ChannelSelection::SingleA0, 1)
+        ],
+        results
+    );
+    destroy(dev);
+}
+
+#[test]
+fn read_scan_of_selected_channel_does_not_write_config() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+    ];
+    let dev = new(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let mut scan = ChannelScan::new([ChannelSelection::DifferentialA0A1]);
+    let first = dev.read_scan(&mut scan, &mut NoopDelay).unwrap();
+    let second = dev.read_scan(&mut scan, &mut NoopDelay).unwrap();
+    assert_eq!((ChannelSelection::DifferentialA0A1, -2048), first);
+    assert_eq!((ChannelSelection::DifferentialA0A1, 2047), second);
+    destroy(dev);
+}
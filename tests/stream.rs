@@ -0,0 +1,119 @@
+use ads1x1x::stream::{Sample, SliceSink, Stream};
+
+mod common;
+use crate::common::{
+    destroy_ads1013, destroy_ads1014, new_ads1013, new_ads1014, BitFlags as BF, Config, I2cTrans,
+    Register, DEVICE_ADDRESS as DEV_ADDR,
+};
+
+fn sample(value: i16, timestamp_us: u32) -> Sample {
+    Sample {
+        value,
+        timestamp_us: Some(timestamp_us),
+    }
+}
+
+#[test]
+fn can_stream_on_ready_pulse() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config_cont = config.with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_cont.msb(), config_cont.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x00, 0x10]),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.use_alert_rdy_pin_as_ready().ok().unwrap();
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let mut buffer = [sample(0, 0); 2];
+    let mut stream = Stream::new(SliceSink::new(&mut buffer));
+    dev.stream_ready(&mut stream, Some(10)).unwrap();
+    dev.stream_ready(&mut stream, None).unwrap();
+    dev.stream_ready(&mut stream, Some(30)).unwrap();
+    assert_eq!(1, stream.overruns());
+    let expected = [
+        sample(-2048, 10),
+        Sample {
+            value: 2047,
+            timestamp_us: None,
+        },
+    ];
+    assert_eq!(&expected, stream.sink().samples());
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_stream_by_polling() {
+    // 1600 SPS: 688 us conversion time including the oscillator tolerance
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+    ];
+    let dev = new_ads1013(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let mut buffer = [sample(0, 0); 4];
+    let mut stream = Stream::new(SliceSink::new(&mut buffer));
+    assert_would_block!(dev.stream_poll(&mut stream, 0));
+    dev.stream_poll(&mut stream, 688).unwrap();
+    assert_would_block!(dev.stream_poll(&mut stream, 1000));
+    // Two conversions finish unread before this poll.
+    dev.stream_poll(&mut stream, 2762).unwrap();
+    assert_eq!(2, stream.overruns());
+    assert_eq!(
+        &[sample(-2048, 688), sample(2047, 2762)],
+        stream.sink().samples()
+    );
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn counts_overruns_with_the_nominal_conversion_period() {
+    // 1600 SPS: 625 us between conversions
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+    ];
+    let dev = new_ads1013(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let mut buffer = [sample(0, 0); 4];
+    let mut stream = Stream::new(SliceSink::new(&mut buffer));
+    assert_would_block!(dev.stream_poll(&mut stream, 0));
+    dev.stream_poll(&mut stream, 688).unwrap();
+    // Ten more conversions finish after the next fresh one at 1376 us.
+    dev.stream_poll(&mut stream, 1376 + 10 * 625).unwrap();
+    assert_eq!(10, stream.overruns());
+    destroy_ads1013(dev);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn can_stream_into_ring_buffer() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+    ];
+    let dev = new_ads1013(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let mut stream = Stream::new(heapless::Deque::<Sample, 1>::new());
+    assert_would_block!(dev.stream_poll(&mut stream, 0));
+    dev.stream_poll(&mut stream, 688).unwrap();
+    dev.stream_poll(&mut stream, 1376).unwrap();
+    assert_eq!(1, stream.overruns());
+    assert_eq!(Some(sample(-2048, 688)), stream.sink_mut().pop_front());
+    destroy_ads1013(dev);
+}
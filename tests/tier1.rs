@@ -1,11 +1,14 @@
-use ads1x1x::{channel, DataRate12Bit, DataRate16Bit};
-use embedded_hal_mock::i2c::Transaction as I2cTrans;
+use ads1x1x::{
+    channel, ChannelSelection, Clipping, ComparatorLatching, ComparatorMode, ComparatorPolarity,
+    ComparatorQueue, Configuration, DataRate12Bit, DataRate16Bit, DeviceRegister, Error,
+    FullScaleRange, Measurement, OperatingMode, Operation, Sequence, SequenceStep,
+};
 use nb::block;
 
 mod common;
 use crate::common::{
-    destroy_ads1013, destroy_ads1113, new_ads1013, new_ads1113, BitFlags as BF, Config, Register,
-    DEVICE_ADDRESS as DEV_ADDR,
+    destroy_ads1013, destroy_ads1113, i2c_error, new_ads1013, new_ads1113, BitFlags as BF, Config,
+    I2cTrans, NoopDelay, Register, DEVICE_ADDRESS as DEV_ADDR,
 };
 
 macro_rules! measure_tests {
@@ -58,6 +61,45 @@ macro_rules! measure_tests {
                 $destroy(dev);
             }
 
+            #[test]
+            fn can_measure_voltage() {
+                let default_config = Config::default();
+                let transactions = [
+                    I2cTrans::write_read(
+                        DEV_ADDR,
+                        vec![Register::CONFIG],
+                        vec![default_config.msb(), default_config.lsb()],
+                    ),
+                    I2cTrans::write(
+                        DEV_ADDR,
+                        vec![Register::CONFIG, default_config.msb(), default_config.lsb()],
+                    ),
+                    I2cTrans::write_read(
+                        DEV_ADDR,
+                        vec![Register::CONFIG],
+                        vec![default_config.msb(), default_config.lsb()],
+                    ),
+                    I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+                ];
+                let mut dev = $create(&transactions);
+                let voltage = block!(dev.read_voltage(&mut channel::DifferentialA0A1)).unwrap();
+                assert_eq!(-2.048, voltage);
+                $destroy(dev);
+            }
+
+            #[test]
+            fn can_measure_millivolts_continuous() {
+                let config = Config::default().with_low(BF::OP_MODE);
+                let transactions = [
+                    I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+                    I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x40, 0x00]),
+                ];
+                let dev = $create(&transactions);
+                let mut dev = dev.into_continuous().ok().unwrap();
+                assert_eq!(1024, dev.read_millivolts().unwrap());
+                $destroy(dev);
+            }
+
             #[test]
             fn can_measure_continuous() {
                 let config = Config::default().with_low(BF::OP_MODE);
@@ -276,6 +318,103 @@ fn can_convert_to_continuous() {
     destroy_ads1013(dev);
 }
 
+#[test]
+fn read_new_returns_each_measurement_once() {
+    // 1600 SPS: 688 us conversion time including the oscillator tolerance
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+    ];
+    let dev = new_ads1013(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let start = u32::MAX - 100;
+    assert_would_block!(dev.read_new(start));
+    assert_would_block!(dev.read_new(start.wrapping_add(687)));
+    assert_eq!(-2048, dev.read_new(start.wrapping_add(688)).unwrap());
+    assert_would_block!(dev.read_new(start.wrapping_add(700)));
+    assert_eq!(2047, dev.read_new(start.wrapping_add(1376)).unwrap());
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn read_new_discards_conversion_ongoing_during_configuration_change() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let config_3300 = config.with_high(BF::DR1);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_3300.msb(), config_3300.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+    ];
+    let dev = new_ads1013(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    assert_would_block!(dev.read_new(0));
+    assert_eq!(-2048, dev.read_new(688).unwrap());
+    dev.set_data_rate(DataRate12Bit::Sps3300).unwrap();
+    // 3300 SPS: 334 us conversion time, preceded by the ongoing 688 us one
+    assert_would_block!(dev.read_new(700));
+    assert_would_block!(dev.read_new(700 + 688 + 333));
+    assert_eq!(2047, dev.read_new(700 + 688 + 334).unwrap());
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn can_flag_clipped_measurements() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xE0]),
+    ];
+    let dev = new_ads1013(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    let measurement = dev.read_measurement().unwrap();
+    assert_eq!(
+        Measurement {
+            raw: 2047,
+            clipping: Some(Clipping::Positive),
+        },
+        measurement
+    );
+    assert!(measurement.is_clipped());
+    assert_eq!(
+        Some(Clipping::Negative),
+        dev.read_measurement().unwrap().clipping
+    );
+    assert!(!dev.read_measurement().unwrap().is_clipped());
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn can_return_out_of_range_error() {
+    let config = Config::default().with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x00, 0x10]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let dev = new_ads1013(&transactions);
+    let mut dev = dev.into_continuous().ok().unwrap();
+    assert!(!dev.out_of_range_error());
+    dev.set_out_of_range_error(true);
+    assert!(dev.out_of_range_error());
+    match dev.read() {
+        Err(Error::OutOfRange) => (),
+        _ => panic!("OutOfRange error was not returned."),
+    }
+    assert_eq!(1, dev.read().unwrap());
+    dev.set_out_of_range_error(false);
+    assert_eq!(-2048, dev.read().unwrap());
+    destroy_ads1013(dev);
+}
+
 #[test]
 fn can_convert_to_one_shot() {
     let config_cont = Config::default().with_low(BF::OP_MODE);
@@ -295,3 +434,395 @@ fn can_convert_to_one_shot() {
     let dev = dev.into_one_shot().ok().unwrap();
     destroy_ads1013(dev);
 }
+
+#[test]
+fn can_read_default_configuration() {
+    let config = Config::default();
+    let transactions = [I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config.msb(), config.lsb()],
+    )];
+    let mut dev = new_ads1013(&transactions);
+    let configuration = dev.read_configuration().unwrap();
+    assert_eq!(
+        Configuration {
+            channel: ChannelSelection::DifferentialA0A1,
+            full_scale_range: FullScaleRange::Within2_048V,
+            operating_mode: OperatingMode::OneShot,
+            data_rate: DataRate12Bit::Sps1600,
+            comparator_mode: ComparatorMode::Traditional,
+            comparator_polarity: ComparatorPolarity::ActiveLow,
+            comparator_latching: ComparatorLatching::Nonlatching,
+            comparator_queue: None,
+        },
+        configuration
+    );
+    destroy_ads1013(dev);
+}
+
+fn custom_config() -> Config {
+    Config::default()
+        .with_low(BF::OS)
+        .with_high(BF::MUX2)
+        .with_high(BF::MUX1)
+        .with_high(BF::MUX0)
+        .with_low(BF::PGA1)
+        .with_low(BF::OP_MODE)
+        .with_high(BF::DR2)
+        .with_high(BF::DR1)
+        .with_high(BF::DR0)
+        .with_high(BF::COMP_MODE)
+        .with_high(BF::COMP_POL)
+        .with_high(BF::COMP_LAT)
+        .with_low(BF::COMP_QUE1)
+        .with_high(BF::COMP_QUE0)
+}
+
+#[test]
+fn can_read_custom_configuration() {
+    let config = custom_config();
+    let transactions = [I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config.msb(), config.lsb()],
+    )];
+    let mut dev = new_ads1113(&transactions);
+    let configuration = dev.read_configuration().unwrap();
+    assert_eq!(
+        Configuration {
+            channel: ChannelSelection::SingleA3,
+            full_scale_range: FullScaleRange::Within6_144V,
+            operating_mode: OperatingMode::Continuous,
+            data_rate: DataRate16Bit::Sps860,
+            comparator_mode: ComparatorMode::Window,
+            comparator_polarity: ComparatorPolarity::ActiveHigh,
+            comparator_latching: ComparatorLatching::Latching,
+            comparator_queue: Some(ComparatorQueue::Two),
+        },
+        configuration
+    );
+    destroy_ads1113(dev);
+}
+
+#[test]
+fn can_sync_configuration_from_device() {
+    let config = custom_config();
+    let expected = config
+        .with_high(BF::OS)
+        .with_high(BF::OP_MODE)
+        .with_high(BF::DR2)
+        .with_low(BF::DR1)
+        .with_low(BF::DR0);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, expected.msb(), expected.lsb()],
+        ),
+    ];
+    let mut dev = new_ads1013(&transactions);
+    let configuration = dev.sync_from_device().unwrap();
+    assert_eq!(OperatingMode::Continuous, configuration.operating_mode);
+    dev.set_data_rate(DataRate12Bit::Sps1600).unwrap();
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn sync_keeps_full_scale_range_fixed() {
+    use embedded_hal::adc::OneShot;
+    // PGA bits selecting ±6.144 V, which have no effect in the ADS1x13
+    let config = Config::default().with_low(BF::PGA1);
+    let read_config = I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config.msb(), config.lsb()],
+    );
+    let transactions = [
+        read_config.clone(),
+        read_config.clone(),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        read_config,
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xF0]),
+    ];
+    let mut dev = new_ads1013(&transactions);
+    dev.sync_from_device().unwrap();
+    assert_would_block!(dev.read(&mut channel::DifferentialA0A1));
+    assert_eq!(
+        2047,
+        block!(dev.read_millivolts(&mut channel::DifferentialA0A1)).unwrap()
+    );
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn configure_keeps_full_scale_range_fixed() {
+    use embedded_hal::adc::OneShot;
+    // PGA bits selecting ±6.144 V, which have no effect in the ADS1x13
+    let config = Config::default().with_low(BF::PGA1);
+    let config_dr = config.with_low(BF::DR2);
+    let config_dr_os = config_dr.with_high(BF::OS);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_dr.msb(), config_dr.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_dr.msb(), config_dr.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_dr_os.msb(), config_dr_os.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_dr_os.msb(), config_dr_os.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x40, 0x00]),
+    ];
+    let mut dev = new_ads1113(&transactions);
+    dev.sync_from_device().unwrap();
+    dev.configure(|c| c.data_rate(DataRate16Bit::Sps8)).unwrap();
+    assert_would_block!(dev.read(&mut channel::DifferentialA0A1));
+    assert_eq!(
+        1024,
+        block!(dev.read_millivolts(&mut channel::DifferentialA0A1)).unwrap()
+    );
+    destroy_ads1113(dev);
+}
+
+#[test]
+fn can_get_cached_settings() {
+    let config = Config::default().with_high(BF::DR0);
+    let transactions = [I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config.msb(), config.lsb()],
+    )];
+    let mut dev = new_ads1113(&transactions);
+    assert_eq!(DataRate16Bit::Sps128, dev.data_rate());
+    dev.set_data_rate(DataRate16Bit::Sps250).unwrap();
+    assert_eq!(DataRate16Bit::Sps250, dev.data_rate());
+    assert_eq!(ChannelSelection::DifferentialA0A1, dev.selected_channel());
+    assert_eq!(OperatingMode::OneShot, dev.configuration().operating_mode);
+    destroy_ads1113(dev);
+}
+
+#[test]
+fn can_configure_data_rate() {
+    let config = Config::default().with_low(BF::DR2);
+    let transactions = [I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config.msb(), config.lsb()],
+    )];
+    let mut dev = new_ads1013(&transactions);
+    dev.configure(|c| c.data_rate(DataRate12Bit::Sps128))
+        .unwrap();
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn can_measure_blocking() {
+    let config_os = Config::default().with_high(BF::OS);
+    let config_in_progress = Config::default().with_low(BF::OS);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_in_progress.msb(), config_in_progress.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut dev = new_ads1113(&transactions);
+    let measurement = dev
+        .read_blocking(&mut channel::DifferentialA0A1, &mut NoopDelay)
+        .unwrap();
+    assert_eq!(-32768, measurement);
+    destroy_ads1113(dev);
+}
+
+#[test]
+fn read_blocking_can_return_out_of_range_error() {
+    let config_os = Config::default().with_high(BF::OS);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x7F, 0xFF]),
+    ];
+    let mut dev = new_ads1113(&transactions);
+    dev.set_out_of_range_error(true);
+    match dev.read_blocking(&mut channel::DifferentialA0A1, &mut NoopDelay) {
+        Err(Error::OutOfRange) => (),
+        _ => panic!("OutOfRange error was not returned."),
+    }
+    destroy_ads1113(dev);
+}
+
+#[test]
+fn cannot_read_sequence_with_full_scale_range() {
+    let mut dev = new_ads1013(&[]);
+    let mut sequence = Sequence::new([SequenceStep::new(ChannelSelection::DifferentialA0A1)
+        .with_full_scale_range(FullScaleRange::Within4_096V)]);
+    match dev.read_sequence(&mut sequence) {
+        Err(nb::Error::Other(Error::InvalidInputData)) => (),
+        _ => panic!("InvalidInputData error was not returned."),
+    }
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn read_blocking_times_out() {
+    let config_os = Config::default().with_high(BF::OS);
+    let config_in_progress = Config::default().with_low(BF::OS);
+    let mut transactions = vec![I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+    )];
+    for _ in 0..16 {
+        transactions.push(I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_in_progress.msb(), config_in_progress.lsb()],
+        ));
+    }
+    // the conversion is triggered again
+    transactions.push(I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+    ));
+    let mut dev = new_ads1013(&transactions);
+    match dev.read_blocking(&mut channel::DifferentialA0A1, &mut NoopDelay) {
+        Err(Error::Timeout) => (),
+        _ => panic!("Timeout error was not returned."),
+    }
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn read_times_out_and_triggers_conversion_again() {
+    use embedded_hal::adc::OneShot;
+    let config_os = Config::default().with_high(BF::OS);
+    let config_in_progress = Config::default().with_low(BF::OS);
+    let poll_in_progress = I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config_in_progress.msb(), config_in_progress.lsb()],
+    );
+    let trigger = I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+    );
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_os.msb(), config_os.lsb()],
+        ),
+        trigger.clone(),
+        poll_in_progress.clone(),
+        poll_in_progress,
+        trigger,
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut dev = new_ads1113(&transactions);
+    dev.set_conversion_poll_limit(Some(2));
+    assert_eq!(Some(2), dev.conversion_poll_limit());
+    assert_would_block!(dev.read(&mut channel::DifferentialA0A1));
+    assert_would_block!(dev.read(&mut channel::DifferentialA0A1));
+    match dev.read(&mut channel::DifferentialA0A1) {
+        Err(nb::Error::Other(Error::Timeout)) => (),
+        _ => panic!("Timeout error was not returned."),
+    }
+    assert_eq!(
+        -32768,
+        block!(dev.read(&mut channel::DifferentialA0A1)).unwrap()
+    );
+    destroy_ads1113(dev);
+}
+
+#[test]
+fn read_detects_device_reset() {
+    use embedded_hal::adc::OneShot;
+    let config = Config::default()
+        .with_low(BF::DR2)
+        .with_low(BF::DR1)
+        .with_low(BF::DR0);
+    let default_config = Config::default();
+    let read_config = I2cTrans::write_read(
+        DEV_ADDR,
+        vec![Register::CONFIG],
+        vec![config.msb(), config.lsb()],
+    );
+    let trigger = I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]);
+    let transactions = [
+        trigger.clone(),
+        read_config.clone(),
+        trigger.clone(),
+        // the device was reset during the conversion
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![default_config.msb(), default_config.lsb()],
+        ),
+        trigger,
+        read_config,
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+    ];
+    let mut dev = new_ads1013(&transactions);
+    dev.set_data_rate(DataRate12Bit::Sps128).unwrap();
+    assert_would_block!(dev.read(&mut channel::DifferentialA0A1));
+    match dev.read(&mut channel::DifferentialA0A1) {
+        Err(nb::Error::Other(Error::ConfigurationChanged)) => (),
+        _ => panic!("ConfigurationChanged error was not returned."),
+    }
+    assert_eq!(
+        -2048,
+        block!(dev.read(&mut channel::DifferentialA0A1)).unwrap()
+    );
+    destroy_ads1013(dev);
+}
+
+#[test]
+fn i2c_error_contains_operation() {
+    let transactions = [
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONFIG], vec![0, 0]).with_error(i2c_error()),
+    ];
+    let mut dev = new_ads1013(&transactions);
+    match dev.read_configuration() {
+        Err(Error::I2C(_, Operation::ReadRegister(DeviceRegister::Config))) => (),
+        _ => panic!("Wrong error returned."),
+    }
+    destroy_ads1013(dev);
+}
@@ -1,11 +1,12 @@
 use ads1x1x::{
-    ComparatorLatching, ComparatorMode, ComparatorPolarity, ComparatorQueue, FullScaleRange,
+    channel, AutoRange, ComparatorLatching, ComparatorMode, ComparatorPolarity, ComparatorQueue,
+    DataRate12Bit, Error, FullScaleRange, OperatingMode, SlaveAddr,
 };
-use embedded_hal_mock::i2c::Transaction as I2cTrans;
 
 mod common;
 use crate::common::{
-    destroy_ads1014, new_ads1014, BitFlags as BF, Config, Register, DEVICE_ADDRESS as DEV_ADDR,
+    destroy_ads1014, new_ads1014, BitFlags as BF, Config, I2cTrans, NoopDelay, PinMock, PinState,
+    PinTrans, Register, DEVICE_ADDRESS as DEV_ADDR,
 };
 
 macro_rules! set_value_test {
@@ -32,6 +33,91 @@ mod can_set_comparator_thresholds {
     set_value_test!(high, set_high_threshold_raw, 2047, HIGH_TH, 0x7F, 0xF0);
 }
 
+mod can_set_comparator_thresholds_voltage {
+    use super::*;
+    set_value_test!(low, set_low_threshold_voltage, 1.5, LOW_TH, 0x5D, 0xC0);
+    set_value_test!(high, set_high_threshold_voltage, -1.5, HIGH_TH, 0xA2, 0x40);
+
+    #[test]
+    fn window() {
+        let transactions = [
+            I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0xA2, 0x40]),
+            I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x5D, 0xC0]),
+        ];
+        let mut dev = new_ads1014(&transactions);
+        dev.set_threshold_window_voltage(-1.5, 1.5).unwrap();
+        destroy_ads1014(dev);
+    }
+
+    #[test]
+    fn uses_full_scale_range() {
+        let config = Config::default()
+            .with_low(BF::PGA2)
+            .with_low(BF::PGA1)
+            .with_low(BF::PGA0);
+        let transactions = [
+            I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+            I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0x7F, 0xF0]),
+        ];
+        let mut dev = new_ads1014(&transactions);
+        dev.set_full_scale_range(FullScaleRange::Within6_144V)
+            .unwrap();
+        dev.set_high_threshold_voltage(6.141).unwrap();
+        destroy_ads1014(dev);
+    }
+
+    macro_rules! invalid_test {
+        ($name:ident, $method:ident, $($value:expr),+) => {
+            #[test]
+            fn $name() {
+                let mut dev = new_ads1014(&[]);
+                match dev.$method($($value),+) {
+                    Err(Error::InvalidInputData) => (),
+                    _ => panic!("InvalidInputData error was not returned."),
+                }
+                destroy_ads1014(dev);
+            }
+        };
+    }
+
+    invalid_test!(low_out_of_range, set_low_threshold_voltage, -2.1);
+    invalid_test!(high_out_of_range, set_high_threshold_voltage, 2.048);
+    invalid_test!(window_out_of_range, set_threshold_window_voltage, 0.0, 2.1);
+    invalid_test!(window_inverted, set_threshold_window_voltage, 1.0, 0.5);
+}
+
+mod can_read_comparator_thresholds {
+    use super::*;
+
+    macro_rules! read_test {
+        ($name:ident, $method:ident, $reg:ident, $msb:expr, $lsb:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let transactions = [I2cTrans::write_read(
+                    DEV_ADDR,
+                    vec![Register::$reg],
+                    vec![$msb, $lsb],
+                )];
+                let mut dev = new_ads1014(&transactions);
+                assert_eq!($expected, dev.$method().unwrap());
+                destroy_ads1014(dev);
+            }
+        };
+    }
+
+    read_test!(low, low_threshold_raw, LOW_TH, 0x7F, 0xF0, 2047);
+    read_test!(high, high_threshold_raw, HIGH_TH, 0x80, 0x00, -2048);
+    read_test!(low_voltage, low_threshold_voltage, LOW_TH, 0xA2, 0x40, -1.5);
+    read_test!(
+        high_voltage,
+        high_threshold_voltage,
+        HIGH_TH,
+        0x5D,
+        0xC0,
+        1.5
+    );
+}
+
 mod can_set_comparator_mode {
     use super::*;
     config_test!(
@@ -80,42 +166,42 @@ mod can_set_comparator_latching {
     );
 }
 
-#[test]
-fn can_disable_comparator() {
-    let config = Config::default()
-        .with_high(BF::COMP_QUE1)
-        .with_high(BF::COMP_QUE0);
-    let transactions = [I2cTrans::write(
-        DEV_ADDR,
-        vec![Register::CONFIG, config.msb(), config.lsb()],
-    )];
-    let mut dev = new_ads1014(&transactions);
-    dev.disable_comparator().unwrap();
-    destroy_ads1014(dev);
-}
-
-mod can_set_comparator_queue {
+mod can_enable_comparator {
     use super::*;
-    config_test!(
+
+    macro_rules! enable_test {
+        ($name:ident, $queue:ident, $config:expr) => {
+            #[test]
+            fn $name() {
+                let config = $config;
+                let transactions = [I2cTrans::write(
+                    DEV_ADDR,
+                    vec![Register::CONFIG, config.msb(), config.lsb()],
+                )];
+                let dev = new_ads1014(&transactions);
+                let dev = dev.enable_comparator(ComparatorQueue::$queue).ok().unwrap();
+                destroy_ads1014(dev);
+            }
+        };
+    }
+
+    enable_test!(
         one,
-        set_comparator_queue,
-        ComparatorQueue::One,
+        One,
         Config::default()
             .with_low(BF::COMP_QUE1)
             .with_low(BF::COMP_QUE0)
     );
-    config_test!(
+    enable_test!(
         two,
-        set_comparator_queue,
-        ComparatorQueue::Two,
+        Two,
         Config::default()
             .with_low(BF::COMP_QUE1)
             .with_high(BF::COMP_QUE0)
     );
-    config_test!(
+    enable_test!(
         four,
-        set_comparator_queue,
-        ComparatorQueue::Four,
+        Four,
         Config::default()
             .with_high(BF::COMP_QUE1)
             .with_low(BF::COMP_QUE0)
@@ -123,40 +209,258 @@ mod can_set_comparator_queue {
 }
 
 #[test]
-fn can_use_alert_rdy_pin_as_rdy_does_not_disable_comparator_if_already_disabled() {
+fn can_set_comparator_queue() {
+    let config_one = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config_four = Config::default()
+        .with_high(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_one.msb(), config_one.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_four.msb(), config_four.lsb()],
+        ),
+    ];
+    let dev = new_ads1014(&transactions);
+    let mut dev = dev.enable_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.set_comparator_queue(ComparatorQueue::Four).unwrap();
+    assert_eq!(Some(ComparatorQueue::Four), dev.comparator_queue());
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_disable_comparator() {
+    let config_one = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config = Config::default();
     let transactions = [
-        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
-        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_one.msb(), config_one.lsb()],
+        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.enable_comparator(ComparatorQueue::One).ok().unwrap();
+    let dev = dev.disable_comparator().ok().unwrap();
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn sync_fails_if_comparator_enabled_but_disabled_in_driver() {
+    let config_one = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0)
+        .with_low(BF::PGA1);
+    let config = Config::default().with_high(BF::DR0);
+    let transactions = [
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_one.msb(), config_one.lsb()],
+        ),
+        // the cached configuration is unchanged
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
     ];
     let mut dev = new_ads1014(&transactions);
-    dev.use_alert_rdy_pin_as_ready().unwrap();
+    match dev.sync_from_device() {
+        Err(Error::ComparatorMismatch) => (),
+        _ => panic!("ComparatorMismatch error was not returned."),
+    }
+    assert_eq!(FullScaleRange::Within2_048V, dev.full_scale_range());
+    dev.set_data_rate(DataRate12Bit::Sps2400).unwrap();
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn sync_fails_if_comparator_disabled_but_enabled_in_driver() {
+    let config_one = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config = Config::default();
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_one.msb(), config_one.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ),
+    ];
+    let dev = new_ads1014(&transactions);
+    let mut dev = dev.enable_comparator(ComparatorQueue::One).ok().unwrap();
+    match dev.sync_from_device() {
+        Err(Error::ComparatorMismatch) => (),
+        _ => panic!("ComparatorMismatch error was not returned."),
+    }
+    assert_eq!(Some(ComparatorQueue::One), dev.comparator_queue());
     destroy_ads1014(dev);
 }
 
 #[test]
-fn can_use_alert_rdy_pin_as_rdy_disabled_comparator() {
+fn can_sync_comparator_configuration() {
+    let config_one = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config_four = config_one.with_high(BF::COMP_QUE1).with_low(BF::PGA1);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_one.msb(), config_one.lsb()],
+        ),
+        I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config_four.msb(), config_four.lsb()],
+        ),
+    ];
+    let dev = new_ads1014(&transactions);
+    let mut dev = dev.enable_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.sync_from_device().unwrap();
+    assert_eq!(Some(ComparatorQueue::Four), dev.comparator_queue());
+    assert_eq!(FullScaleRange::Within6_144V, dev.full_scale_range());
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_use_alert_rdy_pin_as_rdy_enables_comparator_queue() {
     let config = Config::default()
         .with_low(BF::COMP_QUE1)
         .with_low(BF::COMP_QUE0);
-    let config_disabled_comp = Config::default()
-        .with_high(BF::COMP_QUE1)
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.use_alert_rdy_pin_as_ready().ok().unwrap();
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_use_alert_rdy_pin_as_rdy_keeps_comparator_queue() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
         .with_high(BF::COMP_QUE0);
     let transactions = [
         I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.enable_comparator(ComparatorQueue::Two).ok().unwrap();
+    let dev = dev.use_alert_rdy_pin_as_ready().ok().unwrap();
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_disable_alert_rdy_pin() {
+    let config_rdy = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config = Config::default();
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
         I2cTrans::write(
             DEV_ADDR,
-            vec![
-                Register::CONFIG,
-                config_disabled_comp.msb(),
-                config_disabled_comp.lsb(),
-            ],
+            vec![Register::CONFIG, config_rdy.msb(), config_rdy.lsb()],
         ),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.use_alert_rdy_pin_as_ready().ok().unwrap();
+    let dev = dev.disable_comparator().ok().unwrap();
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_read_with_ready_pin() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config_os = config.with_high(BF::OS);
+    let transactions = [
         I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
         I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
     ];
-    let mut dev = new_ads1014(&transactions);
-    dev.set_comparator_queue(ComparatorQueue::One).unwrap();
-    dev.use_alert_rdy_pin_as_ready().unwrap();
+    let mut pin = PinMock::new(&[PinTrans::get(PinState::High), PinTrans::get(PinState::Low)]);
+    let dev = new_ads1014(&transactions);
+    let mut dev = dev.use_alert_rdy_pin_as_ready().ok().unwrap();
+    assert_would_block!(dev.read_with_ready_pin(&mut channel::DifferentialA0A1, &mut pin));
+    assert_would_block!(dev.read_with_ready_pin(&mut channel::DifferentialA0A1, &mut pin));
+    let measurement = dev
+        .read_with_ready_pin(&mut channel::DifferentialA0A1, &mut pin)
+        .unwrap();
+    assert_eq!(-2048, measurement);
+    destroy_ads1014(dev);
+    pin.done();
+}
+
+#[test]
+fn read_with_ready_pin_times_out() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config_os = config.with_high(BF::OS);
+    let trigger = I2cTrans::write(
+        DEV_ADDR,
+        vec![Register::CONFIG, config_os.msb(), config_os.lsb()],
+    );
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::HIGH_TH, 0b1000_0000, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0, 0]),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        trigger.clone(),
+        trigger,
+    ];
+    let mut pin = PinMock::new(&[PinTrans::get(PinState::High), PinTrans::get(PinState::High)]);
+    let dev = new_ads1014(&transactions);
+    let mut dev = dev.use_alert_rdy_pin_as_ready().ok().unwrap();
+    dev.set_conversion_poll_limit(Some(2));
+    assert_would_block!(dev.read_with_ready_pin(&mut channel::DifferentialA0A1, &mut pin));
+    assert_would_block!(dev.read_with_ready_pin(&mut channel::DifferentialA0A1, &mut pin));
+    match dev.read_with_ready_pin(&mut channel::DifferentialA0A1, &mut pin) {
+        Err(nb::Error::Other(Error::Timeout)) => (),
+        _ => panic!("Timeout error was not returned."),
+    }
+    destroy_ads1014(dev);
+    pin.done();
+}
+
+#[test]
+fn mode_change_keeps_alert_rdy_pin_role() {
+    let config = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let config_cont = config.with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_cont.msb(), config_cont.lsb()],
+        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::LOW_TH, 0x7F, 0xF0]),
+    ];
+    let dev = new_ads1014(&transactions);
+    let dev = dev.enable_comparator(ComparatorQueue::One).ok().unwrap();
+    let mut dev = dev.into_continuous().ok().unwrap();
+    dev.set_low_threshold_raw(2047).unwrap();
     destroy_ads1014(dev);
 }
 
@@ -217,3 +521,244 @@ mod can_set_full_scale_range {
             .with_high(BF::PGA0)
     );
 }
+
+#[test]
+fn measurements_are_scaled_with_full_scale_range() {
+    let config = Config::default()
+        .with_high(BF::PGA2)
+        .with_low(BF::PGA1)
+        .with_high(BF::PGA0);
+    let config_cont = config.with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_cont.msb(), config_cont.lsb()],
+        ),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x80, 0x00]),
+        I2cTrans::write_read(DEV_ADDR, vec![Register::CONVERSION], vec![0x40, 0x00]),
+    ];
+    let mut dev = new_ads1014(&transactions);
+    dev.set_full_scale_range(FullScaleRange::Within0_256V)
+        .unwrap();
+    let mut dev = dev.into_continuous().ok().unwrap();
+    assert_eq!(-0.256, dev.read_voltage().unwrap());
+    assert_eq!(128, dev.read_millivolts().unwrap());
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_get_cached_settings() {
+    let config_fsr = Config::default().with_low(BF::PGA1).with_high(BF::PGA0);
+    let config_comp = config_fsr.with_high(BF::COMP_MODE);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_fsr.msb(), config_fsr.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_comp.msb(), config_comp.lsb()],
+        ),
+    ];
+    let mut dev = new_ads1014(&transactions);
+    assert_eq!(FullScaleRange::Within2_048V, dev.full_scale_range());
+    assert_eq!(None, dev.comparator_queue());
+    dev.set_full_scale_range(FullScaleRange::Within4_096V)
+        .unwrap();
+    dev.set_comparator_mode(ComparatorMode::Window).unwrap();
+    assert_eq!(FullScaleRange::Within4_096V, dev.full_scale_range());
+    assert_eq!(ComparatorMode::Window, dev.comparator_mode());
+    assert_eq!(ComparatorPolarity::ActiveLow, dev.comparator_polarity());
+    assert_eq!(ComparatorLatching::Nonlatching, dev.comparator_latching());
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_configure_in_single_write() {
+    let config = Config::default()
+        .with_low(BF::PGA1)
+        .with_high(BF::PGA0)
+        .with_high(BF::COMP_MODE)
+        .with_high(BF::COMP_POL)
+        .with_high(BF::COMP_LAT)
+        .with_low(BF::COMP_QUE1)
+        .with_high(BF::COMP_QUE0)
+        .with_high(BF::DR0);
+    let config_one = Config::default()
+        .with_low(BF::COMP_QUE1)
+        .with_low(BF::COMP_QUE0);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_one.msb(), config_one.lsb()],
+        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+    ];
+    let dev = new_ads1014(&transactions);
+    let mut dev = dev.enable_comparator(ComparatorQueue::One).ok().unwrap();
+    dev.configure(|c| {
+        c.data_rate(DataRate12Bit::Sps2400)
+            .full_scale_range(FullScaleRange::Within4_096V)
+            .comparator_mode(ComparatorMode::Window)
+            .comparator_polarity(ComparatorPolarity::ActiveHigh)
+            .comparator_latching(ComparatorLatching::Latching)
+            .comparator_queue(ComparatorQueue::Two)
+    })
+    .unwrap();
+    assert_eq!(FullScaleRange::Within4_096V, dev.full_scale_range());
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn general_call_reset_resets_driver_state() {
+    let config_fsr = Config::default().with_low(BF::PGA1).with_high(BF::PGA0);
+    let config_comp = config_fsr.with_low(BF::COMP_QUE1).with_low(BF::COMP_QUE0);
+    let config_cont = config_comp.with_low(BF::OP_MODE);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_fsr.msb(), config_fsr.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_comp.msb(), config_comp.lsb()],
+        ),
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_cont.msb(), config_cont.lsb()],
+        ),
+        I2cTrans::write(0x00, vec![0x06]),
+    ];
+    let mut dev = new_ads1014(&transactions);
+    dev.set_full_scale_range(FullScaleRange::Within4_096V)
+        .unwrap();
+    let dev = dev.enable_comparator(ComparatorQueue::One).ok().unwrap();
+    let dev = dev.into_continuous().ok().unwrap();
+    let dev = dev.general_call_reset().ok().unwrap();
+    assert_eq!(FullScaleRange::Within2_048V, dev.full_scale_range());
+    assert_eq!(None, dev.comparator_queue());
+    assert_eq!(OperatingMode::OneShot, dev.configuration().operating_mode);
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn can_read_alert_response() {
+    let config_lat = Config::default().with_high(BF::COMP_LAT);
+    let config = config_lat.with_low(BF::COMP_QUE1).with_low(BF::COMP_QUE0);
+    let transactions = [
+        I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config_lat.msb(), config_lat.lsb()],
+        ),
+        I2cTrans::write(DEV_ADDR, vec![Register::CONFIG, config.msb(), config.lsb()]),
+        I2cTrans::read(0x0C, vec![DEV_ADDR << 1]),
+    ];
+    let mut dev = new_ads1014(&transactions);
+    dev.set_comparator_latching(ComparatorLatching::Latching)
+        .unwrap();
+    let mut dev = dev.enable_comparator(ComparatorQueue::One).ok().unwrap();
+    assert_eq!(
+        Some(SlaveAddr::default()),
+        dev.read_alert_response().unwrap()
+    );
+    destroy_ads1014(dev);
+}
+
+fn auto_range_transactions(conversions: &[(FullScaleRange, i16)]) -> Vec<I2cTrans> {
+    let mut transactions = Vec::new();
+    for (range, value) in conversions {
+        let config = Config::default().with_high(BF::OS);
+        let config = match range {
+            FullScaleRange::Within6_144V => config.with_low(BF::PGA1),
+            FullScaleRange::Within4_096V => config.with_low(BF::PGA1).with_high(BF::PGA0),
+            FullScaleRange::Within2_048V => config,
+            FullScaleRange::Within1_024V => config.with_high(BF::PGA0),
+            FullScaleRange::Within0_512V => config.with_high(BF::PGA2).with_low(BF::PGA1),
+            FullScaleRange::Within0_256V => config
+                .with_high(BF::PGA2)
+                .with_low(BF::PGA1)
+                .with_high(BF::PGA0),
+        };
+        let data = ((value << 4) as u16).to_be_bytes().to_vec();
+        transactions.push(I2cTrans::write(
+            DEV_ADDR,
+            vec![Register::CONFIG, config.msb(), config.lsb()],
+        ));
+        transactions.push(I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONFIG],
+            vec![config.msb(), config.lsb()],
+        ));
+        transactions.push(I2cTrans::write_read(
+            DEV_ADDR,
+            vec![Register::CONVERSION],
+            data,
+        ));
+    }
+    transactions
+}
+
+#[test]
+fn auto_range_widens_range_when_saturated() {
+    let transactions = auto_range_transactions(&[
+        (FullScaleRange::Within2_048V, 2047),
+        (FullScaleRange::Within4_096V, 1200),
+    ]);
+    let mut dev = new_ads1014(&transactions);
+    let mut auto_range = AutoRange::new(FullScaleRange::Within2_048V);
+    let measurement = dev
+        .read_auto_range(
+            &mut channel::DifferentialA0A1,
+            &mut auto_range,
+            &mut NoopDelay,
+        )
+        .unwrap();
+    assert_eq!(1200, measurement.raw);
+    assert_eq!(2.4, measurement.volts);
+    assert_eq!(FullScaleRange::Within4_096V, measurement.full_scale_range);
+    assert_eq!(FullScaleRange::Within4_096V, auto_range.full_scale_range());
+    assert_eq!(FullScaleRange::Within4_096V, dev.full_scale_range());
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn auto_range_narrows_range_for_small_measurements() {
+    let transactions = auto_range_transactions(&[
+        (FullScaleRange::Within2_048V, -300),
+        (FullScaleRange::Within1_024V, -600),
+        (FullScaleRange::Within0_512V, -1200),
+    ]);
+    let mut dev = new_ads1014(&transactions);
+    let mut auto_range = AutoRange::new(FullScaleRange::Within2_048V);
+    let measurement = dev
+        .read_auto_range(
+            &mut channel::DifferentialA0A1,
+            &mut auto_range,
+            &mut NoopDelay,
+        )
+        .unwrap();
+    assert_eq!(-1200, measurement.raw);
+    assert_eq!(FullScaleRange::Within0_512V, measurement.full_scale_range);
+    destroy_ads1014(dev);
+}
+
+#[test]
+fn auto_range_does_not_narrow_range_after_widening_it() {
+    let transactions = auto_range_transactions(&[
+        (FullScaleRange::Within1_024V, -2048),
+        (FullScaleRange::Within2_048V, -700),
+    ]);
+    let mut dev = new_ads1014(&transactions);
+    let mut auto_range = AutoRange::new(FullScaleRange::Within1_024V).with_step_down_fraction(0.45);
+    let measurement = dev
+        .read_auto_range(
+            &mut channel::DifferentialA0A1,
+            &mut auto_range,
+            &mut NoopDelay,
+        )
+        .unwrap();
+    assert_eq!(-700, measurement.raw);
+    assert_eq!(FullScaleRange::Within2_048V, measurement.full_scale_range);
+    destroy_ads1014(dev);
+}